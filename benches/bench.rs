@@ -12,6 +12,7 @@ fn tokenize(input: &str) -> Vec<Vec<String>> {
 pub const TS: &[(&str, &str)] = &[
     ("business", include_str!("../tests/test_business.txt")),
     ("google", include_str!("../tests/test_google.txt")),
+    ("references", include_str!("../tests/test_references.txt")),
     ("turkish", include_str!("../tests/test_turkish.txt")),
 ];
 
@@ -31,6 +32,26 @@ fn benchmark(cr: &mut Criterion) {
     gr.finish();
 }
 
+fn tokenizer(cr: &mut Criterion) {
+    use segtok::{segmenter::*, tokenizer::*};
+
+    segtok::init();
+    let mut gr = cr.benchmark_group("tokenizer");
+
+    for &(name, text) in TS {
+        let size = text.len() as u64;
+        let sentences = split_multi(text, Default::default());
+
+        gr.throughput(Throughput::Bytes(size)).bench_with_input(
+            BenchmarkId::new(name, size),
+            &sentences,
+            |b, sentences| b.iter(|| sentences.iter().map(|s| word_tokenizer(s)).collect::<Vec<_>>()),
+        );
+    }
+
+    gr.finish();
+}
+
 fn is_terminal(cr: &mut Criterion) {
     let mut gr = cr.benchmark_group("is_terminal");
 
@@ -88,5 +109,5 @@ fn is_terminal(cr: &mut Criterion) {
     gr.finish();
 }
 
-criterion_group!(benches, benchmark);
+criterion_group!(benches, benchmark, tokenizer);
 criterion_main!(benches);
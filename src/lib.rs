@@ -1,6 +1,7 @@
 //! A rule-based sentence segmenter (splitter) and a word tokenizer using orthographic features.
 //! Ported from the [python package](https://github.com/fnl/segtok) (not maintained anymore),
-//! and fixes the [contractions bug](https://github.com/fnl/segtok/issues/26).
+//! and fixes the [contractions bug](https://github.com/fnl/segtok/issues/26), unless the
+//! [python_compat](tokenizer::TokenizeConfig::python_compat) mode reproduces it.
 //!
 //! ```rust
 //! use segtok::{segmenter::*, tokenizer::*};
@@ -9,7 +10,7 @@
 //!
 //! let sentences: Vec<Vec<_>> = split_multi(input, SegmentConfig::default())
 //!     .into_iter()
-//!     .map(|span| split_contractions(web_tokenizer(&span)).collect())
+//!     .map(|span| split_contractions(web_tokenizer(&span)))
 //!     .collect();
 //! ```
 
@@ -26,20 +27,33 @@ pub fn init() {
     let _ = segmenter::dates::ENDS_IN_DATE_DIGITS.deref();
     let _ = segmenter::BEFORE_LOWER.deref();
     let _ = segmenter::LOWER_WORD.deref();
+    let _ = segmenter::LOWER_START_WORD.deref();
     let _ = segmenter::MIDDLE_INITIAL_END.deref();
+    let _ = segmenter::BEFORE_NUMBER.deref();
     let _ = segmenter::UPPER_WORD_START.deref();
     let _ = segmenter::LONE_WORD.deref();
+    let _ = segmenter::OUTLINE_ITEM.deref();
     let _ = segmenter::UPPER_CASE_END.deref();
     let _ = segmenter::UPPER_CASE_START.deref();
     let _ = segmenter::DO_NOT_CROSS_LINES.deref();
     let _ = segmenter::MAY_CROSS_ONE_LINE.deref();
     let _ = segmenter::ABBREVIATIONS.deref();
+    let _ = segmenter::PORTUGUESE_ABBREVIATIONS.deref();
+    let _ = segmenter::STREET.deref();
     let _ = segmenter::CONTINUATIONS.deref();
+    let _ = segmenter::INLINE_MATH.deref();
+    let _ = segmenter::SPEAKER_LABEL.deref();
+    let _ = segmenter::NON_UNIX_LINEBREAK.deref();
 
+    let _ = tokenizer::AMOUNT.deref();
+    let _ = tokenizer::CURRENCY.deref();
     let _ = tokenizer::HYPHENATED_LINEBREAK.deref();
     let _ = tokenizer::IS_CONTRACTION.deref();
     let _ = tokenizer::IS_POSSESSIVE.deref();
+    let _ = tokenizer::MEASUREMENT.deref();
+    let _ = tokenizer::SEMVER.deref();
     let _ = tokenizer::SYMBOLIC.deref();
     let _ = tokenizer::URI_OR_MAIL.deref();
     let _ = tokenizer::WORD_BITS.deref();
+    let _ = tokenizer::WORD_BITS_WITH_UNDERSCORES.deref();
 }
@@ -1,4 +1,16 @@
-use fancy_regex::{Matches, Regex};
+use fancy_regex::{Error, Matches, Regex};
+
+/// A character class matching any of the `chars`.
+pub(crate) fn char_class(chars: impl Iterator<Item = char>) -> String {
+    let chars = chars.map(|ch| format!("\\u{{{:04X}}}", ch as u32)).collect::<String>();
+
+    // an empty class is not valid, so match nothing instead
+    if chars.is_empty() {
+        r"[^\s\S]".to_owned()
+    } else {
+        format!("[{chars}]")
+    }
+}
 
 #[derive(Debug, Copy, Clone)]
 pub enum Partition<'s> {
@@ -50,48 +62,54 @@ impl<'r, 't> PartitionIter<'r, 't> {
     pub fn new(re: &'r Regex, text: &'t str) -> PartitionIter<'r, 't> {
         PartitionIter { it: re.find_iter(text), last_match_end: 0, text, next_match: None }
     }
-}
-
-impl<'t> Iterator for PartitionIter<'_, 't> {
-    type Item = Partition<'t>;
 
-    fn next(&mut self) -> Option<Partition<'t>> {
+    /// The next partition, or the error of the regex, like an exceeded backtrack limit.
+    pub fn try_next(&mut self) -> Option<Result<Partition<'t>, Error>> {
         if let Some(next_match) = self.next_match.take() {
-            return Some(Partition::Match(next_match));
+            return Some(Ok(Partition::Match(next_match)));
         }
-        match self.it.next().map(Result::unwrap) {
+        match self.it.next() {
             None => {
                 if self.last_match_end >= self.text.len() {
                     None
                 } else {
                     let non_match = &self.text[self.last_match_end..];
                     self.last_match_end = self.text.len();
-                    Some(Partition::NonMatch(non_match))
+                    Some(Ok(Partition::NonMatch(non_match)))
                 }
             }
-            Some(m) => {
+            Some(Err(err)) => Some(Err(err)),
+            Some(Ok(m)) => {
                 if m.start() > self.last_match_end {
                     let non_match = &self.text[self.last_match_end..m.start()];
                     self.last_match_end = m.end();
                     self.next_match = Some(m.as_str());
-                    Some(Partition::NonMatch(non_match))
+                    Some(Ok(Partition::NonMatch(non_match)))
                 } else {
                     self.last_match_end = m.end();
-                    Some(Partition::Match(m.as_str()))
+                    Some(Ok(Partition::Match(m.as_str())))
                 }
             }
         }
     }
 }
 
+impl<'t> Iterator for PartitionIter<'_, 't> {
+    type Item = Partition<'t>;
+
+    fn next(&mut self) -> Option<Partition<'t>> {
+        self.try_next().map(Result::unwrap)
+    }
+}
+
 pub trait RegexSplitExt {
     /// Split `target` by the occurrences of regex pattern.
     /// The text of all groups in the pattern are also returned as part of the resulting list.
-    fn split_with_separators<'r, 'h>(&'r self, target: &'h str) -> impl Iterator<Item = &'h str> + Sized;
+    fn split_with_separators<'h>(&self, target: &'h str) -> impl Iterator<Item = &'h str> + Sized;
 }
 
 impl RegexSplitExt for Regex {
-    fn split_with_separators<'r, 'h>(&'r self, target: &'h str) -> impl Iterator<Item = &'h str> + Sized {
+    fn split_with_separators<'h>(&self, target: &'h str) -> impl Iterator<Item = &'h str> + Sized {
         PartitionIter::new(self, target).map(Partition::into_inner)
     }
 }
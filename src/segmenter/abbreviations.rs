@@ -1,15 +1,19 @@
+use std::collections::BTreeMap;
 use std::sync::LazyLock;
 
 use fancy_regex::Regex;
 
-use crate::segmenter::HYPHENS;
+use crate::segmenter::{Lang, HYPHENS};
 
 /// Common abbreviations at the candidate sentence end that normally don't terminate a sentence.
 /// Note that a check is required to ensure the potential abbreviation is actually followed
 /// by a dot and not some other sentence segmentation marker.
+///
+/// These are the English and German ones; see [abbreviations_for] for the other languages.
 pub static ABBREVIATIONS: LazyLock<Regex> = LazyLock::new(|| {
     // Only abbreviations that should never occur at the end of a sentence (such as "etc.")
-    let list = r#"
+    abbreviations_pattern(
+        r#"
        approx
     |  cf
     |  med
@@ -33,11 +37,52 @@ pub static ABBREVIATIONS: LazyLock<Regex> = LazyLock::new(|| {
     |  [Pp](?: hil | rof | \.e )
     |  [Rr]er
     |  S(?: ci | ept? | gt | r (?: a | ta )? | t )
+    |  Tabs?
     |  U(?: niv | \.[KS] )
     |  Vol
     |  Vs
     |  [Zz]\.B
-    "#;
+    "#,
+    )
+});
+
+/// The [ABBREVIATIONS] of Portuguese, like "Sra", "pág", "séc", or the dotted "V.Exa", with the same rules
+/// for single chars, digits, initials, and case citations.
+pub static PORTUGUESE_ABBREVIATIONS: LazyLock<Regex> = LazyLock::new(|| {
+    abbreviations_pattern(
+        r#"
+       [Aa](?: rt | v )
+    |  [Cc] ap
+    |  [Dd] ra?
+    |  [Ee] xm[oa] s?
+    |  [Nn] úm
+    |  [Pp](?: ágs? | rofa? )
+    |  [Ss](?: é[cr] | ra? | rta )
+    |  V \. Exa s?
+    |  [Vv] ol
+    |  e\.?g
+    |  [Ii] (?: \.?[ev] )
+    "#,
+    )
+});
+
+/// The known abbreviations of the language, used by the segmenter for its [SegmentConfig::language].
+///
+/// ```rust
+/// use segtok::segmenter::{abbreviations_for, Lang};
+///
+/// assert!(abbreviations_for(Lang::Portuguese).is_match("Ver pág").unwrap());
+/// assert!(abbreviations_for(Lang::English).is_match("see Fig").unwrap());
+/// ```
+pub fn abbreviations_for(lang: Lang) -> &'static Regex {
+    match lang {
+        Lang::Portuguese => &PORTUGUESE_ABBREVIATIONS,
+        _ => &ABBREVIATIONS,
+    }
+}
+
+/// The pattern of the abbreviations of the `list` of alternatives, in verbose mode, and of the common rules.
+fn abbreviations_pattern(list: &str) -> Regex {
     Regex::new(&format!(
         r#"(?ux)
         (?: \b(?:{list}) # 1. known abbreviations,
@@ -63,10 +108,121 @@ pub static ABBREVIATIONS: LazyLock<Regex> = LazyLock::new(|| {
                 [\p{{Lu}}\p{{Lt}}] \p{{Lm}}? \. # optional A.
                 [{HYPHENS}]?                    # optional hyphen
             )? [\p{{Lu}}\p{{Lt}}] \p{{Lm}}?     # required A
+        |   \b\p{{Lu}}\S*\s v # 5. the "v" (versus) of a case citation, like "Roe v. Wade"
     ) $"#
     ))
     .unwrap()
-});
+}
+
+/// "St" after a street name, as in "on Main St" or "5 Elm St", abbreviates "Street" and may end a sentence,
+/// while the [ABBREVIATIONS] treat it as "Saint", as in "St. Louis".
+pub static STREET: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\b(?:\p{Ll}+|\p{Nd}+)(?:\s+\p{Lu}\p{Ll}+)+\s+St$"#).unwrap());
+
+/// A user-supplied list of abbreviations, which are matched like the known [ABBREVIATIONS]:
+/// at the end of a candidate sentence, after a word boundary, and before the dot.
+///
+/// The words are kept in a trie of reversed chars, so a match walks back from the end of the candidate
+/// at most the length of the longest word, without any backtracking.
+///
+/// ```rust
+/// use segtok::segmenter::AbbreviationList;
+///
+/// let list = AbbreviationList::new(["Ref", "Sec"]);
+/// assert!(list.is_match("see Ref"));
+/// assert!(!list.is_match("see XRef"));
+/// assert!(AbbreviationList::case_insensitive(["ref"]).is_match("see REF"));
+/// ```
+#[derive(Debug, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct AbbreviationList {
+    root: TrieNode,
+    case_insensitive: bool,
+}
+
+#[derive(Debug, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+struct TrieNode {
+    /// Whether a word starts here, so the path to the root spells it backwards.
+    is_word_start: bool,
+    children: BTreeMap<char, TrieNode>,
+}
+
+impl AbbreviationList {
+    /// A list of case-sensitive abbreviations, without the dots at their ends, like "Fig" or "e.g".
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> Self {
+        Self::build(words, false)
+    }
+
+    /// A list of abbreviations that also match in any other case, so "fig" matches "Fig" and "FIG".
+    pub fn case_insensitive<S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> Self {
+        Self::build(words, true)
+    }
+
+    fn build<S: AsRef<str>>(words: impl IntoIterator<Item = S>, case_insensitive: bool) -> Self {
+        let mut list = Self { root: TrieNode::default(), case_insensitive };
+        list.extend(words);
+        list
+    }
+
+    /// Check if the list has no words.
+    pub fn is_empty(&self) -> bool {
+        self.root.children.is_empty()
+    }
+
+    /// Check if the `span` ends with any of the words, which starts at a word boundary.
+    pub fn is_match(&self, span: &str) -> bool {
+        let is_word_char = |ch: char| ch.is_alphanumeric() || ch == '_';
+
+        let mut node = &self.root;
+        let mut chars = span.chars().rev().peekable();
+
+        while let Some(ch) = chars.next() {
+            for folded in self.fold_char(ch).into_iter().rev() {
+                match node.children.get(&folded) {
+                    Some(child) => node = child,
+                    None => return false,
+                }
+            }
+
+            let at_boundary = chars.peek().map_or(is_word_char(ch), |&before| is_word_char(before) != is_word_char(ch));
+            if node.is_word_start && at_boundary {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn fold(&self, word: &str) -> Vec<char> {
+        word.chars().flat_map(|ch| self.fold_char(ch)).collect()
+    }
+
+    fn fold_char(&self, ch: char) -> Vec<char> {
+        if self.case_insensitive {
+            ch.to_lowercase().collect()
+        } else {
+            vec![ch]
+        }
+    }
+}
+
+/// Add more words to the list, which match in the same case as the others.
+impl<S: AsRef<str>> Extend<S> for AbbreviationList {
+    fn extend<T: IntoIterator<Item = S>>(&mut self, words: T) {
+        for word in words {
+            let word = word.as_ref().trim_end_matches('.');
+            if word.is_empty() {
+                continue;
+            }
+
+            let chars = self.fold(word);
+            let mut node = &mut self.root;
+            for ch in chars.into_iter().rev() {
+                node = node.children.entry(ch).or_default();
+            }
+            node.is_word_start = true;
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -74,7 +230,7 @@ mod tests {
 
     #[test]
     fn abbrevs() {
-        for example in ["Of approx", "12 vs"] {
+        for example in ["Of approx", "12 vs", "see Fig", "in Tab", "in Tabs"] {
             assert!(ABBREVIATIONS.is_match(example).unwrap());
         }
     }
@@ -93,6 +249,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn case_citation() {
+        for example in ["Roe v", "Brown v", "see McCulloch v", "Smith, J. v", "A vs"] {
+            assert!(ABBREVIATIONS.is_match(example).unwrap(), "{example}");
+        }
+        for example in ["said v", "Roe vv", "Roe w", "Roev"] {
+            assert!(!ABBREVIATIONS.is_match(example).unwrap(), "{example}");
+        }
+    }
+
+    #[test]
+    fn portuguese() {
+        let regex = abbreviations_for(Lang::Portuguese);
+        for example in ["o Sr", "a Sra", "a Srta", "Dra", "ao Exmo", "à V.Exa", "ver pág", "no séc", "o núm", "A", "12"]
+        {
+            assert!(regex.is_match(example).unwrap(), "{example}");
+        }
+        for example in ["o Senhor", "a casa", "pé", "Sé", "V.Ex"] {
+            assert!(!regex.is_match(example).unwrap(), "{example}");
+        }
+        assert!(!ABBREVIATIONS.is_match("ver pág").unwrap());
+    }
+
+    #[test]
+    fn street() {
+        for example in ["on Main St", "5 Elm St", "on Martin Luther King St"] {
+            assert!(STREET.is_match(example).unwrap());
+        }
+
+        for example in ["St", "in St", "Visit St", "on Main Street", "on main St"] {
+            assert!(!STREET.is_match(example).unwrap());
+        }
+    }
+
     #[test]
     fn ignore() {
         for example in
@@ -101,4 +291,41 @@ mod tests {
             assert!(!ABBREVIATIONS.is_match(example).unwrap());
         }
     }
+
+    #[test]
+    fn list() {
+        let list = AbbreviationList::new(["Ref", "Sec.", "e.g", "", "_x"]);
+        for example in ["Ref", "see Ref", "(Ref", "see Sec", "as e.g", "a _x"] {
+            assert!(list.is_match(example), "{example}");
+        }
+        for example in ["", "see XRef", "see ref", "Ref ", "see REF", "see Sec.", "as ee.g", "a__x", "f"] {
+            assert!(!list.is_match(example), "{example}");
+        }
+    }
+
+    #[test]
+    fn list_like_regex() {
+        let words = ["Fig", "e.g", "Nr"];
+        let list = AbbreviationList::new(words);
+        let regex = Regex::new(&format!(r#"(?u)\b(?:{})$"#, words.map(fancy_regex::escape).join("|"))).unwrap();
+
+        for example in ["Fig", "see Fig", "seeFig", "1Fig", "_Fig", "Fig.Fig", "i.e.g", "e.g", "-e.g", "ÄNr", "Nr", "x"]
+        {
+            assert_eq!(list.is_match(example), regex.is_match(example).unwrap(), "{example}");
+        }
+    }
+
+    #[test]
+    fn list_case_insensitive() {
+        let list = AbbreviationList::case_insensitive(["ref", "STRAßE"]);
+        for example in ["see Ref", "see REF", "see ref", "straße", "Straße"] {
+            assert!(list.is_match(example), "{example}");
+        }
+        assert!(!list.is_match("see xref"));
+
+        let mut list = list;
+        list.extend(["fig"]);
+        assert!(list.is_match("see FIG") && list.is_match("see Ref"));
+        assert!(AbbreviationList::default().is_empty());
+    }
 }
@@ -0,0 +1,124 @@
+use std::collections::VecDeque;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use super::{segmenter, split_multi, SegmentConfig};
+
+/// A text to segment, and where to send its sentences.
+type Job = (String, Sender<Vec<String>>);
+
+/// Segment the `texts` with [split_multi] on a pool of `concurrency` std threads,
+/// and yield the sentences of each text in the order of the input.
+///
+/// The texts are pulled from the input lazily: at most `concurrency` texts (or their sentences)
+/// are held at a time, so the memory stays bounded by the largest texts, however long the input is,
+/// and a slow consumer holds back the input. A `concurrency` of zero is taken as one.
+///
+/// The workers share the compiled regexes, which are built once per process, like the segmentation pattern
+/// of the `cfg`, which is compiled before the workers start. They stop when the iterator is dropped.
+///
+/// ```rust
+/// use segtok::segmenter::{segment_batch, SegmentConfig};
+///
+/// let texts = ["One. Two.", "Three."].map(String::from);
+/// let sentences = segment_batch(texts, SegmentConfig::default(), 2).collect::<Vec<_>>();
+/// assert_eq!(sentences, [vec!["One.", "Two."], vec!["Three."]]);
+/// ```
+pub fn segment_batch<I>(texts: I, cfg: SegmentConfig, concurrency: usize) -> SegmentBatch<I::IntoIter>
+where
+    I: IntoIterator<Item = String>,
+{
+    let concurrency = concurrency.max(1);
+    // compile the pattern of the config once, for all the workers
+    segmenter(2, &cfg);
+    let (jobs, queue) = channel::<Job>();
+    let queue = Arc::new(Mutex::new(queue));
+
+    for _ in 0..concurrency {
+        let queue = Arc::clone(&queue);
+        let cfg = cfg.clone();
+        thread::spawn(move || loop {
+            // the lock is released before the segmentation, once the job is taken
+            let job = queue.lock().map(|queue| queue.recv());
+            match job {
+                Ok(Ok((text, sentences))) => {
+                    let _ = sentences.send(split_multi(&text, cfg.clone()));
+                }
+                _ => break,
+            }
+        });
+    }
+
+    SegmentBatch { texts: texts.into_iter(), jobs, pending: VecDeque::with_capacity(concurrency), concurrency }
+}
+
+/// The iterator of [segment_batch].
+#[derive(Debug)]
+pub struct SegmentBatch<I> {
+    texts: I,
+    jobs: Sender<Job>,
+    /// The receivers of the texts in flight, in the order of the input.
+    pending: VecDeque<Receiver<Vec<String>>>,
+    concurrency: usize,
+}
+
+impl<I: Iterator<Item = String>> Iterator for SegmentBatch<I> {
+    type Item = Vec<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.len() < self.concurrency {
+            let Some(text) = self.texts.next() else { break };
+            let (sentences, receiver) = channel();
+            self.jobs.send((text, sentences)).expect("the workers of segment_batch stopped");
+            self.pending.push_back(receiver);
+        }
+
+        let receiver = self.pending.pop_front()?;
+        Some(receiver.recv().expect("a worker of segment_batch panicked"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_order() {
+        let texts = (0..200).map(|i| format!("Text {i}. {}End of {i}.", "Some words here. ".repeat(i % 7)));
+        let actual = segment_batch(texts.clone(), SegmentConfig::default(), 4).collect::<Vec<_>>();
+        let expected = texts.map(|text| split_multi(&text, SegmentConfig::default())).collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn bounded() {
+        let pulled = std::cell::Cell::new(0);
+        let texts = (0..100).map(|i| {
+            pulled.set(pulled.get() + 1);
+            format!("Text {i}.")
+        });
+
+        let mut batch = segment_batch(texts, SegmentConfig::default(), 3);
+        assert_eq!(pulled.get(), 0);
+        assert_eq!(batch.next(), Some(vec!["Text 0.".to_string()]));
+        assert_eq!(pulled.get(), 3);
+        assert_eq!(batch.count(), 99);
+    }
+
+    #[test]
+    fn shared_segmenter() {
+        let cfg = SegmentConfig::default().tab_is_boundary(true);
+        let compiled = thread::spawn(move || segmenter(2, &cfg).right().unwrap()).join().unwrap();
+        let cfg = SegmentConfig::default().tab_is_boundary(true);
+        assert!(Arc::ptr_eq(&compiled, &segmenter(2, &cfg).right().unwrap()));
+        assert!(segmenter(2, &SegmentConfig::default()).is_left());
+    }
+
+    #[test]
+    fn no_concurrency() {
+        let texts = ["A b. C d.".to_string()];
+        assert_eq!(segment_batch(texts, SegmentConfig::default(), 0).collect::<Vec<_>>(), [["A b.", "C d."]]);
+        assert_eq!(segment_batch(Vec::new(), SegmentConfig::default(), 2).count(), 0);
+    }
+}
@@ -0,0 +1,121 @@
+use super::{split_multi, SegmentConfig};
+
+/// Segment each block of a document on its own, so sentences are never joined across blocks,
+/// as for the headings, paragraphs, and list items of Markdown or HTML.
+///
+/// A block starts at a line for which `is_block_start` returns `true`, or after an empty line,
+/// and continues up to the next one; so a heading followed by a paragraph needs an empty line in between.
+/// The lines are passed without their linebreaks; [is_markdown_block_start] detects the common blocks.
+/// Returns the [split_multi] sentences of each non-empty block, in order.
+///
+/// ```rust
+/// use segtok::segmenter::{is_markdown_block_start, segment_blocks, SegmentConfig};
+///
+/// let text = "# Intro\n\nSome text. More text\n- item one\n- item two";
+/// assert_eq!(
+///     segment_blocks(text, SegmentConfig::default(), is_markdown_block_start),
+///     [vec!["# Intro"], vec!["Some text.", "More text"], vec!["- item one"], vec!["- item two"]]
+/// );
+/// ```
+pub fn segment_blocks(text: &str, cfg: SegmentConfig, is_block_start: impl Fn(&str) -> bool) -> Vec<Vec<String>> {
+    let mut blocks = Vec::new();
+    let mut start = 0;
+    let mut cursor = 0;
+
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        if content.trim().is_empty() {
+            blocks.push(&text[start..cursor]);
+            start = cursor + line.len();
+        } else if is_block_start(content) {
+            blocks.push(&text[start..cursor]);
+            start = cursor;
+        }
+
+        cursor += line.len();
+    }
+
+    blocks.push(&text[start..]);
+    blocks.into_iter().filter(|block| !block.trim().is_empty()).map(|block| split_multi(block, cfg.clone())).collect()
+}
+
+/// Check if the line starts a Markdown or HTML block: a heading ("# Title"), a list item ("- item", "1. item"),
+/// a quote ("> text"), a fenced code block ("```"), a thematic break ("---"), or a block-level HTML tag ("<p>").
+pub fn is_markdown_block_start(line: &str) -> bool {
+    const HTML_BLOCKS: [&str; 14] =
+        ["p", "h1", "h2", "h3", "h4", "h5", "h6", "li", "ul", "ol", "blockquote", "pre", "div", "table"];
+
+    let line = line.trim_start();
+    let is_marker_end = |pos: usize| line[pos..].is_empty() || line[pos..].starts_with(' ');
+    let hashes = line.len() - line.trim_start_matches('#').len();
+    let digits = line.len() - line.trim_start_matches(|ch: char| ch.is_ascii_digit()).len();
+    let tag = line.strip_prefix('<').map(|rest| {
+        let name = rest.trim_start_matches('/').split(|ch: char| !ch.is_ascii_alphanumeric()).next();
+        name.unwrap_or_default().to_ascii_lowercase()
+    });
+
+    (1..=6).contains(&hashes) && is_marker_end(hashes)
+        || line.starts_with(['-', '*', '+', '>']) && is_marker_end(1)
+        || (1..=3).contains(&digits) && line[digits..].starts_with(['.', ')']) && is_marker_end(digits + 1)
+        || line.starts_with("```")
+        || line.starts_with("---") && line.trim_end().chars().all(|ch| ch == '-')
+        || tag.is_some_and(|tag| HTML_BLOCKS.contains(&tag.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markdown() {
+        let text = "# Title\n\
+                    \n\
+                    An intro. It goes on\n\
+                    over two lines.\n\
+                    \n\
+                    ## Details\n\
+                    - first item\n\
+                    - second item. With more\n\
+                    1. numbered\n\
+                    > quoted text\n\
+                    No heading #hashtag";
+        let expected = [
+            vec!["# Title"],
+            vec!["An intro.", "It goes on\nover two lines."],
+            vec!["## Details"],
+            vec!["- first item"],
+            vec!["- second item.", "With more"],
+            vec!["1. numbered"],
+            vec!["> quoted text\nNo heading #hashtag"],
+        ];
+        assert_eq!(segment_blocks(text, SegmentConfig::default(), is_markdown_block_start), expected);
+    }
+
+    #[test]
+    fn html() {
+        let text = "<h1>Title</h1>\n<p>First para. Has two\nlines</p>\n<P>Second</P>";
+        let expected = [vec!["<h1>Title</h1>"], vec!["<p>First para.", "Has two\nlines</p>"], vec!["<P>Second</P>"]];
+        assert_eq!(segment_blocks(text, SegmentConfig::default(), is_markdown_block_start), expected);
+    }
+
+    #[test]
+    fn blank_lines() {
+        let text = "\n\nOne\nline\n\n\n\nTwo.\r\n\r\nThree\n";
+        let expected = [vec!["One\nline"], vec!["Two."], vec!["Three"]];
+        assert_eq!(segment_blocks(text, SegmentConfig::default(), |_| false), expected);
+        assert!(segment_blocks(" \n", SegmentConfig::default(), |_| true).is_empty());
+    }
+
+    #[test]
+    fn block_starts() {
+        let starts =
+            ["# A", "###", "  - item", "* item", "+ item", ">", "10. item", "3) item", "```rust", "---", "</ul>"];
+        for line in starts {
+            assert!(is_markdown_block_start(line), "{line:?}");
+        }
+        for line in ["#hashtag", "-5 degrees", "*bold*", "1.5 liters", "2024) was", "-- dash", "<span>", "text", ""] {
+            assert!(!is_markdown_block_start(line), "{line:?}");
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use super::{split_multi_with_indent, InternalNewline, SegmentConfig};
+
+/// Check if there is a sentence boundary at the byte position `pos` of the `text`,
+/// as [split_multi] would split it with the same `cfg`.
+///
+/// A boundary spans the gap between two sentences: from the end of one sentence,
+/// right after its terminal and closing quotes or brackets, to the start of the next one.
+/// So in "One. Two.", the positions 4 and 5 are boundaries, while 3 (before the dot) is not.
+/// The start and the end of the `text` are not boundaries, nor are positions inside a char.
+///
+/// Only the context around `pos` is examined: the paragraph containing it,
+/// plus one paragraph before and after, separated by consecutive newlines.
+/// Sentences are rarely joined across paragraphs, and almost never across two of them.
+/// Each call segments this window, so avoid calling it for every position of a long text.
+///
+/// [split_multi]: super::split_multi
+pub fn is_boundary_at(text: &str, pos: usize, cfg: &SegmentConfig) -> bool {
+    if !text.is_char_boundary(pos) {
+        return false;
+    }
+
+    let mut start = pos;
+    let mut end = pos;
+    for _ in 0..2 {
+        // leading newlines change the segmentation, so the window starts right at the text
+        let paragraph = text[..start].trim_end().rfind("\n\n").unwrap_or(0);
+        start = text.len() - text[paragraph..].trim_start().len();
+        let rest = text[end..].trim_start();
+        end = text.len() - rest.len() + rest.find("\n\n").unwrap_or(rest.len());
+    }
+
+    // the sentences must be verbatim slices of the text to locate them
+    let cfg = cfg.clone().internal_newline(InternalNewline::Keep);
+    let mut cursor = start;
+    let mut previous_end = None;
+
+    for (sentence, prefix) in split_multi_with_indent(&text[start..end], cfg) {
+        let sentence_start = cursor + prefix.len();
+        if previous_end.is_some_and(|previous_end| previous_end <= pos) && pos <= sentence_start {
+            return true;
+        }
+
+        cursor = sentence_start + sentence.len();
+        previous_end = Some(cursor);
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn boundaries(text: &str) -> Vec<usize> {
+        (0..=text.len()).filter(|&pos| is_boundary_at(text, pos, &Default::default())).collect()
+    }
+
+    #[test]
+    fn gaps() {
+        assert_eq!(boundaries("One. Two."), [4, 5]);
+        assert_eq!(boundaries("One?!  \"Two.\""), [5, 6, 7]);
+        assert_eq!(boundaries("One.\n\nTwo."), [4, 5, 6]);
+    }
+
+    #[test]
+    fn no_boundaries() {
+        assert!(boundaries("Dr. Smith and Mr. Jones.").is_empty());
+        assert!(boundaries(" One sentence. ").is_empty());
+        assert!(boundaries("").is_empty());
+    }
+
+    #[test]
+    fn same_as_split_multi() {
+        let text = "First paragraph. It is short.\n\nSecond one, etc.\n\nand a continuation. Third (one.\n\nTwo) end.";
+        let cfg = SegmentConfig::default();
+
+        let mut expected = Vec::new();
+        let mut cursor = 0;
+        for (sentence, prefix) in split_multi_with_indent(text, cfg.clone()) {
+            cursor += prefix.len();
+            expected.push(cursor);
+            cursor += sentence.len();
+        }
+
+        // the starts of all but the first sentence are boundaries
+        let actual = (0..=text.len()).filter(|&pos| is_boundary_at(text, pos, &cfg));
+        let starts = actual.filter(|&pos| !text[pos..].starts_with(char::is_whitespace)).collect::<Vec<_>>();
+        assert_eq!(starts, expected[1..]);
+    }
+
+    #[test]
+    fn inside_char() {
+        let text = "Eins. Zwö. Drei.";
+        assert!(!is_boundary_at(text, 9, &Default::default()));
+        assert!(is_boundary_at(text, 11, &Default::default()));
+    }
+}
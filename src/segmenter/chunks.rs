@@ -0,0 +1,85 @@
+use std::ops::Range;
+
+use super::{split_multi_spans, SegmentConfig};
+
+/// Segment the `text` with [split_multi](super::split_multi), and greedily pack the consecutive sentences
+/// into chunks of at most `max_tokens`, as counted by `count_tokens`, like the tokenizer of a language model.
+///
+/// The chunks are slices of the text, from the start of their first sentence to the end of their last one,
+/// so the whitespace between the sentences is kept, and counted with the rest of the chunk.
+/// A sentence of more tokens than the budget is a chunk on its own.
+///
+/// As the chunks are slices, the sentences are located with [split_multi_spans], which ignores the options
+/// that rewrite the text: the [strip_quote_prefix](SegmentConfig::strip_quote_prefix),
+/// the [rule_line_is_break](SegmentConfig::rule_line_is_break), and the
+/// [internal_newline](SegmentConfig::internal_newline).
+///
+/// ```rust
+/// use segtok::segmenter::chunk_sentences;
+///
+/// let words = |sentence: &str| sentence.split_whitespace().count();
+/// let chunks = chunk_sentences("One two. Three four. Five.\n\nSix.", Default::default(), 4, words);
+/// assert_eq!(chunks, ["One two. Three four.", "Five.\n\nSix."]);
+/// ```
+pub fn chunk_sentences(
+    text: &str,
+    cfg: SegmentConfig,
+    max_tokens: usize,
+    count_tokens: impl Fn(&str) -> usize,
+) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut chunk: Option<Range<usize>> = None;
+
+    for span in split_multi_spans(text, cfg) {
+        match &mut chunk {
+            // a tokenizer may merge or split tokens across the whitespace, so the whole slice is counted
+            Some(range) if count_tokens(&text[range.start..span.end]) <= max_tokens => range.end = span.end,
+            _ => chunks.extend(chunk.replace(span).map(|range| text[range].to_owned())),
+        }
+    }
+
+    chunks.extend(chunk.map(|range| text[range].to_owned()));
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(sentence: &str) -> usize {
+        sentence.split_whitespace().count()
+    }
+
+    #[test]
+    fn packs() {
+        let text = "One two three. Four five. Six seven eight nine. Ten.";
+        let chunk = |max_tokens| chunk_sentences(text, Default::default(), max_tokens, words);
+        assert_eq!(chunk(5), ["One two three. Four five.", "Six seven eight nine. Ten."]);
+        assert_eq!(chunk(4), ["One two three.", "Four five.", "Six seven eight nine.", "Ten."]);
+        assert_eq!(chunk(100), [text]);
+    }
+
+    #[test]
+    fn oversized() {
+        let text = "One two three four five six. Seven. Eight.";
+        assert_eq!(
+            chunk_sentences(text, Default::default(), 2, words),
+            ["One two three four five six.", "Seven. Eight."]
+        );
+        assert_eq!(chunk_sentences(text, Default::default(), 0, words).len(), 3);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(chunk_sentences("", Default::default(), 10, words).is_empty());
+        assert!(chunk_sentences(" \n\n ", Default::default(), 10, words).is_empty());
+    }
+
+    #[test]
+    fn counts_the_whole_chunk() {
+        // every char is a token, so the space between the sentences counts, too
+        let chars = |chunk: &str| chunk.chars().count();
+        assert_eq!(chunk_sentences("One. Two.", Default::default(), 8, chars), ["One.", "Two."]);
+        assert_eq!(chunk_sentences("One. Two.", Default::default(), 9, chars), ["One. Two."]);
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::{Arc, LazyLock};
+
+use either::Either;
+use fancy_regex::Regex;
+
+use super::{compiled, terminals_class, SegmentConfig, SegmentError};
+
+/// The name of a speaker label, like "Alice" or "Dr. Smith", of up to three capitalized words before a colon
+/// and a space. As the name must be of letters, times and ratios, like "10:30" or "3:1", are not labels.
+const LABEL: &str = r#"\p{Lu} [\p{L}\p{M}'’.\-]* (?: [^\S\n] \p{Lu} [\p{L}\p{M}'’.\-]* ){0,2} (?= : (?: \s | $ ) )"#;
+
+/// The speaker label at the start of a turn of a transcript, as in "Alice: Hello.",
+/// which the [SegmentConfig::speaker_labels](super::SegmentConfig::speaker_labels) takes as a turn boundary.
+/// The match is the name of the speaker, without the colon.
+pub static SPEAKER_LABEL: LazyLock<Regex> = LazyLock::new(|| Regex::new(&format!("(?x) ^ {LABEL}")).unwrap());
+
+/// A [SPEAKER_LABEL] at the start of a line, or after a terminal of the `terminals` class, its closing brackets
+/// or quotes, and spaces. The label is consumed, so a name like "Dr. Smith" does not start another turn at "Smith".
+fn turn_start_pattern(terminals: &str) -> String {
+    format!(
+        r#"(?mx)
+            (?: ^ [^\S\n]*
+            |   {terminals} [)\]"'”’»]* \s+
+            )
+            ( {LABEL} )
+        "#
+    )
+}
+
+/// The [turn_start_pattern] of the terminals of the [SegmentConfig::default].
+static TURN_START: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&turn_start_pattern(&terminals_class(&SegmentConfig::default()))).unwrap());
+
+/// The [turn_start_pattern] of the terminals of the `cfg`, which is only compiled if they differ from the default.
+fn turn_start(cfg: &SegmentConfig) -> Either<&'static Regex, Arc<Regex>> {
+    let pattern = turn_start_pattern(&terminals_class(cfg));
+    if pattern == TURN_START.as_str() {
+        Either::Left(&TURN_START)
+    } else {
+        Either::Right(compiled(pattern))
+    }
+}
+
+/// The name of the speaker of a sentence that starts with a [SPEAKER_LABEL], like "Alice" of "Alice: Hello."
+///
+/// ```rust
+/// use segtok::segmenter::speaker_label;
+///
+/// assert_eq!(speaker_label("Dr. Smith: Hello."), Some("Dr. Smith"));
+/// assert_eq!(speaker_label("At 10:30 we met."), None);
+/// ```
+pub fn speaker_label(sentence: &str) -> Option<&str> {
+    SPEAKER_LABEL.find(sentence).ok().flatten().map(|label| label.as_str())
+}
+
+/// Split the text into the turns of the speakers, before each label, so the turns are segmented on their own.
+/// The text before the first label, if any, is a turn, too.
+pub(crate) fn speaker_turns<'t>(text: &'t str, cfg: &SegmentConfig) -> Result<Vec<&'t str>, SegmentError> {
+    let mut turns = Vec::new();
+    let mut start = 0;
+
+    for captures in turn_start(cfg).captures_iter(text) {
+        let captures = captures.map_err(|err| SegmentError::regex(text, err))?;
+        let label = captures.get(1).expect("the label is not optional");
+        if label.start() > start {
+            turns.push(&text[start..label.start()]);
+            start = label.start();
+        }
+    }
+
+    turns.push(&text[start..]);
+    Ok(turns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::segmenter::Script;
+
+    #[test]
+    fn labels() {
+        assert_eq!(speaker_label("Alice: Hello."), Some("Alice"));
+        assert_eq!(speaker_label("MARY JANE: Hi."), Some("MARY JANE"));
+        assert_eq!(speaker_label("O’Brien:\nYes."), Some("O’Brien"));
+        assert_eq!(speaker_label("Ratio 3:1 wins."), None);
+        assert_eq!(speaker_label("alice: hi"), None);
+        assert_eq!(speaker_label("Alice:Hello"), None);
+    }
+
+    #[test]
+    fn turns() {
+        let text = "Alice: Hello. Dr. Smith: Hi there.\nBob: At 10:30. Fine.\n  Eve: Bye!";
+        assert_eq!(
+            speaker_turns(text, &SegmentConfig::default()).unwrap(),
+            ["Alice: Hello. ", "Dr. Smith: Hi there.\n", "Bob: At 10:30. Fine.\n  ", "Eve: Bye!"]
+        );
+        assert_eq!(speaker_turns("No labels here.", &SegmentConfig::default()).unwrap(), ["No labels here."]);
+    }
+
+    #[test]
+    fn turns_after_the_terminals_of_the_config() {
+        let text = "Alice: Wait! Bob: What… Eve: ሰላም። Carol: Hi.";
+        let cfg = SegmentConfig::default();
+        assert_eq!(speaker_turns(text, &cfg).unwrap(), ["Alice: Wait! ", "Bob: What… Eve: ሰላም። Carol: Hi."]);
+
+        let cfg = cfg.exclude_terminals(['!']).split_on_ellipsis(true).script(Some(Script::Ethiopic));
+        assert_eq!(speaker_turns(text, &cfg).unwrap(), ["Alice: Wait! Bob: What… ", "Eve: ሰላም። ", "Carol: Hi."]);
+    }
+}
@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+
+use chardetng::EncodingDetector;
+use encoding_rs::{Encoding, UTF_8};
+
+use super::{split_multi, SegmentConfig, SegmentError};
+
+/// Decode `bytes` to UTF-8 and [split_multi] the text.
+///
+/// The encoding is taken from the byte order mark, if any. Otherwise, valid UTF-8 is used as is,
+/// and the encoding of anything else is guessed (e.g., Latin-1 or Windows-1252).
+pub fn split_multi_bytes(bytes: &[u8], cfg: SegmentConfig) -> Result<Vec<String>, SegmentError> {
+    let text = decode(bytes)?;
+    Ok(split_multi(&text, cfg))
+}
+
+/// Decode `bytes` into a string, detecting the encoding.
+fn decode(bytes: &[u8]) -> Result<Cow<'_, str>, SegmentError> {
+    let (encoding, bom_len) = match Encoding::for_bom(bytes) {
+        Some(found) => found,
+        None if std::str::from_utf8(bytes).is_ok() => (UTF_8, 0),
+        None => {
+            let mut detector = EncodingDetector::new();
+            detector.feed(bytes, true);
+            (detector.guess(None, true), 0)
+        }
+    };
+
+    let (text, had_errors) = encoding.decode_without_bom_handling(&bytes[bom_len..]);
+    if had_errors {
+        return Err(SegmentError::Decode { encoding: encoding.name() });
+    }
+
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utf8() {
+        let res = split_multi_bytes("Über den Fluß. Zweiter Satz.".as_bytes(), Default::default());
+        assert_eq!(res.unwrap(), ["Über den Fluß.", "Zweiter Satz."]);
+    }
+
+    #[test]
+    fn utf8_with_bom() {
+        let res = split_multi_bytes(b"\xEF\xBB\xBFFirst one. Second one.", Default::default());
+        assert_eq!(res.unwrap(), ["First one.", "Second one."]);
+    }
+
+    #[test]
+    fn windows_1252() {
+        // “Déjà vu,” he said. Ça va?
+        let bytes = b"\x93D\xE9j\xE0 vu,\x94 he said. \xC7a va?";
+        let res = split_multi_bytes(bytes, Default::default());
+        assert_eq!(res.unwrap(), ["“Déjà vu,” he said.", "Ça va?"]);
+    }
+
+    #[test]
+    fn undecodable() {
+        // UTF-16 with a dangling byte
+        let res = split_multi_bytes(b"\xFF\xFEA\x00.\x00 \x00B", Default::default());
+        assert_eq!(res, Err(SegmentError::Decode { encoding: "UTF-16LE" }));
+    }
+}
@@ -0,0 +1,125 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+/// An error that may occur during segmentation.
+///
+/// Errors are equal if they are of the same kind and their fields are equal,
+/// where the [regex errors](fancy_regex::Error) are compared by their messages.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum SegmentError {
+    /// The input bytes are not valid in the detected encoding.
+    Decode { encoding: &'static str },
+    /// The byte range is out of the bounds of the text, or not on char boundaries.
+    Range { range: Range<usize> },
+    /// A pattern failed on a span of the text, like when it overflowed the stack.
+    /// Only the first [REGEX_SPAN_PREFIX] chars of the span are kept, with its length in bytes.
+    Regex { prefix: String, len: usize, error: Box<fancy_regex::Error> },
+}
+
+/// How many chars of the span a [SegmentError::Regex] keeps, so a failure on a large text does not copy it.
+pub const REGEX_SPAN_PREFIX: usize = 40;
+
+impl SegmentError {
+    /// Wrap the `error` of a pattern that failed on the `span`.
+    pub(crate) fn regex(span: &str, error: impl Into<Box<fancy_regex::Error>>) -> Self {
+        let prefix = span.char_indices().nth(REGEX_SPAN_PREFIX).map_or(span, |(end, _)| &span[..end]);
+        SegmentError::Regex { prefix: prefix.to_owned(), len: span.len(), error: error.into() }
+    }
+}
+
+impl Display for SegmentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SegmentError::Decode { encoding } => write!(f, "input is not valid {encoding}"),
+            SegmentError::Range { range } => {
+                write!(f, "range {range:?} is out of bounds or not on char boundaries of the input")
+            }
+            SegmentError::Regex { prefix, len, error } => {
+                write!(f, "pattern failed on a span of {len} bytes starting with {prefix:?}: {error}")
+            }
+        }
+    }
+}
+
+impl Error for SegmentError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SegmentError::Regex { error, .. } => Some(&**error),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for SegmentError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (SegmentError::Decode { encoding: a }, SegmentError::Decode { encoding: b }) => a == b,
+            (SegmentError::Range { range: a }, SegmentError::Range { range: b }) => a == b,
+            (
+                SegmentError::Regex { prefix: a, len: n, error: x },
+                SegmentError::Regex { prefix: b, len: m, error: y },
+            ) => (a, n) == (b, m) && x.to_string() == y.to_string(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for SegmentError {}
+
+impl Hash for SegmentError {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            SegmentError::Decode { encoding } => encoding.hash(state),
+            SegmentError::Range { range } => range.hash(state),
+            SegmentError::Regex { prefix, len, error } => (prefix, len, error.to_string()).hash(state),
+        }
+    }
+}
+
+/// An error in a [SegmentConfig](super::SegmentConfig), found by [validate](super::SegmentConfig::validate).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ConfigError {
+    /// The segmentation pattern derived from the config does not compile.
+    Pattern { line_breaks: usize, message: String },
+    /// A no-start token is empty or has surrounding whitespace, so it never matches as intended.
+    NoStartToken { token: String },
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Pattern { line_breaks, message } => {
+                write!(f, "segmentation pattern for {line_breaks} line break(s) does not compile: {message}")
+            }
+            ConfigError::NoStartToken { token } => {
+                write!(f, "no-start token {token:?} is empty or surrounded by whitespace")
+            }
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regex() {
+        let error = fancy_regex::Regex::new("(").unwrap_err();
+        let span = "é".repeat(1000);
+        let err = SegmentError::regex(&span, error.clone());
+
+        let SegmentError::Regex { prefix, len, .. } = &err else { panic!("{err:?}") };
+        assert_eq!((prefix.chars().count(), *len), (REGEX_SPAN_PREFIX, 2000));
+        assert!(err.to_string().starts_with("pattern failed on a span of 2000 bytes starting with \"éé"));
+        assert_eq!(err.source().map(ToString::to_string), Some(error.to_string()));
+        assert_eq!(err, SegmentError::regex(&span, error));
+        assert_eq!(SegmentError::Range { range: 0..1 }.source().map(ToString::to_string), None);
+    }
+}
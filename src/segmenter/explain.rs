@@ -0,0 +1,150 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::{try_split_multi_iter, Hooks, SegmentConfig};
+
+/// The heuristic of [split_multi](super::split_multi) that joined a span to the sentence before it.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum MergeKind {
+    /// The terminal ends an abbreviation, like "Dr." or "e.g.", an initial, like "J.",
+    /// an outline item, like "A.", or a day before a month, like "3. Mai".
+    Abbreviation,
+    /// The span starts with a word that continues a sentence, like "and",
+    /// or with one of the [no_start_tokens](SegmentConfig::no_start_tokens).
+    Continuation,
+    /// A bracket is still open, and either fragment is shorter than the
+    /// [short_sentence_length](SegmentConfig::short_sentence_length).
+    BracketShortFragment,
+    /// The span starts with a lower-case word, like "musculus" in "m. musculus".
+    LowercaseFollow,
+    /// A quote of the [balance_quotes](SegmentConfig::balance_quotes) language is still open.
+    OpenQuote,
+    /// The terminal is separated from the word before it by a space, like in "see . Next",
+    /// or continues a run of terminals, like the second "?!" in "Wow?! ?! Next".
+    DetachedTerminal,
+}
+
+/// A span that [split_multi](super::split_multi) joined to the sentence before it, instead of splitting.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct MergedDueTo {
+    pub kind: MergeKind,
+    /// The text that decided the merge, depending on the [kind](MergeKind):
+    /// the abbreviation without its dot, the continuation or lower-case word, the shorter bracket fragment,
+    /// the open quote, or the detached terminal.
+    pub text: String,
+    /// The byte offset of the joined span in the text.
+    pub offset: usize,
+}
+
+/// Run the segmentation of [split_multi](super::split_multi), and report every merge of a span
+/// into the sentence before it, in the order of the text.
+///
+/// Useful to debug over- and under-splitting, as each merge names the abbreviation or word that caused it.
+/// The merges of the [min_sentence_tokens](SegmentConfig::min_sentence_tokens) rule are not reported.
+/// The offsets are in the `text` as given, before the normalization of its linebreaks or its quote prefixes.
+///
+/// ```rust
+/// use segtok::segmenter::{explain_merges, MergeKind, SegmentConfig};
+///
+/// let merges = explain_merges("Ask Dr. Smith. He knows.", SegmentConfig::default());
+/// assert_eq!(merges.len(), 1);
+/// assert_eq!((merges[0].kind, merges[0].text.as_str(), merges[0].offset), (MergeKind::Abbreviation, "Dr", 8));
+/// ```
+pub fn explain_merges(text: &str, cfg: SegmentConfig) -> Vec<MergedDueTo> {
+    let merges = RefCell::new(Vec::new());
+    let on_merge = |kind, text: &str, offset| merges.borrow_mut().push(MergedDueTo { kind, text: text.into(), offset });
+    try_split_multi_iter(Cow::Borrowed(text), cfg, None, Hooks { on_merge: Rc::new(on_merge), ..Hooks::default() })
+        .for_each(|sentence| {
+            sentence.unwrap();
+        });
+
+    // the abbreviations are joined in a pass before the other heuristics
+    let mut merges = merges.into_inner();
+    merges.sort_by_key(|merge| merge.offset);
+    merges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::segmenter::split_multi;
+
+    fn explain(text: &str, cfg: SegmentConfig) -> Vec<(MergeKind, String)> {
+        explain_merges(text, cfg).into_iter().map(|merge| (merge.kind, merge.text)).collect()
+    }
+
+    #[test]
+    fn abbreviations() {
+        let actual = explain("Compare e.g. Fig. 3 with the work of Smith J. Miller here.", SegmentConfig::default());
+        let expected = [
+            (MergeKind::Abbreviation, "e.g".to_string()),
+            (MergeKind::Abbreviation, "Fig".to_string()),
+            (MergeKind::Abbreviation, "J".to_string()),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn continuations() {
+        let actual = explain("It was the largest city. and the oldest one. So it goes.", SegmentConfig::default());
+        assert_eq!(actual, [(MergeKind::Continuation, "and".to_string())]);
+
+        let cfg = SegmentConfig::default().no_start_tokens(vec!["Inc".to_string()]);
+        assert_eq!(explain("Made by Acme. Inc is big.", cfg), [(MergeKind::Continuation, "Inc".to_string())]);
+    }
+
+    #[test]
+    fn lowercase() {
+        let actual = explain("The mouse m. musculus is small.", SegmentConfig::default());
+        assert_eq!(actual, [(MergeKind::LowercaseFollow, "musculus".to_string())]);
+    }
+
+    #[test]
+    fn brackets() {
+        let actual = explain("Values (see Fig. 2. Left) differ.", SegmentConfig::default());
+        assert_eq!(
+            actual,
+            [
+                (MergeKind::Abbreviation, "Fig".to_string()),
+                (MergeKind::BracketShortFragment, "Left) differ.".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn terminal_runs() {
+        let actual = explain("Hey. . . You. Wow?! ?! Next.", SegmentConfig::default());
+        let expected =
+            [(MergeKind::DetachedTerminal, ". .".to_string()), (MergeKind::DetachedTerminal, "?!".to_string())];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn offsets() {
+        let text = "The mouse m. musculus and e.g. Dr. Smith.";
+        for merge in explain_merges(text, SegmentConfig::default()) {
+            assert!(text[merge.offset..].starts_with(|ch: char| !ch.is_whitespace()), "{merge:?}");
+        }
+        assert_eq!(explain_merges("One. Two.", SegmentConfig::default()), []);
+    }
+
+    #[test]
+    fn pipeline() {
+        // the speaker turn splits at "Dr.", and the dot inside the math is masked
+        let text = "Bob: I met Dr. Alice: Hi there.\r\n\r\nAnd Dr. Who (see $a. b$ and more) came.";
+        let cfg = SegmentConfig::default().speaker_labels(true).inline_math(true);
+        let sentences = split_multi(text, cfg.clone());
+        assert_eq!(sentences, ["Bob: I met Dr.", "Alice: Hi there.", "And Dr. Who (see $a. b$ and more) came."]);
+
+        let merges = explain_merges(text, cfg);
+        assert_eq!(merges, [MergedDueTo { kind: MergeKind::Abbreviation, text: "Dr".to_string(), offset: 43 }]);
+        assert!(text[merges[0].offset..].starts_with("Who"));
+
+        let text = "> Ask Dr. Smith.\r\n> And e.g. more.";
+        let merges = explain_merges(text, SegmentConfig::default().strip_quote_prefix(true));
+        let rest = merges.iter().map(|merge| &text[merge.offset..]).collect::<Vec<_>>();
+        assert_eq!(rest, ["Smith.\r\n> And e.g. more.", "more."]);
+    }
+}
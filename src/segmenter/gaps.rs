@@ -0,0 +1,144 @@
+use std::ops::Range;
+
+use super::{segmenter, split_multi, split_multi_with_indent, SegmentConfig};
+use crate::regex::RegexSplitExt;
+
+/// The same as [split_multi], but each sentence is paired with the separator that follows it
+/// in the `text`: the spaces after a sentence terminal, or the newlines of a paragraph break.
+///
+/// The terminal itself, like the "." or "?!", remains part of the sentence.
+/// Separators inside a sentence, for example after a joined abbreviation ("Dr. Smith") or a
+/// single newline, are kept verbatim in the sentence and are never reported as separators.
+/// The separator of the last sentence is the trailing whitespace of the `text`.
+///
+/// Joining all the sentences and separators restores the `text` without its leading whitespace
+/// (see [split_multi_with_indent] to keep it), unless internal newlines are replaced with
+/// [InternalNewline::Space](super::InternalNewline::Space).
+pub fn split_multi_with_gaps(text: &str, cfg: SegmentConfig) -> Vec<(String, String)> {
+    let mut res = split_multi_with_indent(text, cfg);
+    let end = res.iter().map(|(sentence, prefix)| prefix.len() + sentence.len()).sum::<usize>();
+
+    // shift the prefixes by one, so each separator follows its sentence instead of preceding it
+    let mut separator = text[end..].to_owned();
+    for (_, prefix) in res.iter_mut().rev() {
+        separator = std::mem::replace(prefix, separator);
+    }
+
+    res
+}
+
+/// The sentences of a text, together with the whitespace after the last one.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Segmented {
+    pub sentences: Vec<String>,
+    /// Whitespace at the very end of the text, like a final newline, which the sentences never contain.
+    pub trailing_whitespace: String,
+}
+
+/// The same as [split_multi], but also keeps the whitespace at the end of the `text`,
+/// so it is known if a document ended with a newline.
+pub fn split_multi_with_trailing(text: &str, cfg: SegmentConfig) -> Segmented {
+    let trailing_whitespace = text[text.trim_end().len()..].to_owned();
+    Segmented { sentences: split_multi(text, cfg), trailing_whitespace }
+}
+
+/// The same as [split_multi_with_gaps], but the sentences are not trimmed: each one spans the `text` from the end
+/// of the previous separator up to the next one, so whitespace that is part of the content is kept,
+/// like the indentation of the first line of a paragraph.
+///
+/// The separators are the whitespace after a sentence terminal, or the newlines of a paragraph break.
+/// The separator of the last sentence is the trailing whitespace of the `text`, with or without a terminal.
+/// Joining all the sentences and separators restores the `text`, unless it is blank.
+/// Internal newlines are always kept, see [InternalNewline::Keep](super::InternalNewline::Keep).
+///
+/// ```rust
+/// use segtok::segmenter::split_multi_untrimmed;
+///
+/// let text = "  Indented one. Two\n\n\tThree ";
+/// let sentences = split_multi_untrimmed(text, Default::default());
+/// let expected = [("  Indented one.", " "), ("Two", "\n\n"), ("\tThree", " ")];
+/// assert_eq!(sentences, expected.map(|(sentence, separator)| (sentence.into(), separator.into())));
+/// ```
+pub fn split_multi_untrimmed(text: &str, cfg: SegmentConfig) -> Vec<(String, String)> {
+    let offset = |span: &str| span.as_ptr() as usize - text.as_ptr() as usize;
+
+    // the whitespace at the end of each sentence separator, without the terminals
+    let separators = segmenter(2, &cfg)
+        .split_with_separators(text)
+        .skip(1)
+        .step_by(2)
+        .map(|marker| offset(marker) + marker.trim_end().len()..offset(marker) + marker.len())
+        .collect::<Vec<Range<usize>>>();
+
+    let mut res = Vec::<(Range<usize>, usize)>::new();
+    let mut start = 0;
+    let mut cursor = 0;
+
+    // the trimmed sentences are verbatim slices of the text to locate them
+    for sentence in split_multi(text, cfg.verbatim()) {
+        let rest = &text[cursor..];
+        cursor += rest.len() - rest.trim_start().len() + sentence.len();
+
+        let separator = separators.get(separators.partition_point(|separator| separator.end <= cursor));
+        let end = separator.map_or(text.len(), |separator| separator.start.max(cursor));
+        let next = separator.map_or(text.len(), |separator| separator.end);
+        res.push((start..end, next));
+        start = next;
+    }
+
+    // the trailing whitespace is the separator of the last sentence, as in split_multi_with_gaps
+    if let Some((span, next)) = res.last_mut() {
+        span.end = span.end.min(text.trim_end().len());
+        *next = text.len();
+    }
+
+    res.into_iter().map(|(span, next)| (text[span.clone()].to_owned(), text[span.end..next].to_owned())).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gaps() {
+        let text = "  First one?! Dr. Smith said so.\n\nNext\nparagraph. \n";
+        let expected = [("First one?!", " "), ("Dr. Smith said so.", "\n\n"), ("Next\nparagraph.", " \n")];
+        let actual = split_multi_with_gaps(text, Default::default());
+        assert_eq!(actual, expected.map(|(a, b)| (a.to_owned(), b.to_owned())));
+
+        let restored = actual.into_iter().map(|(sentence, separator)| sentence + &separator).collect::<String>();
+        assert_eq!(restored, text.trim_start());
+    }
+
+    #[test]
+    fn no_trailing_whitespace() {
+        let actual = split_multi_with_gaps("One. Two.", Default::default());
+        assert_eq!(actual, [("One.".to_owned(), " ".to_owned()), ("Two.".to_owned(), "".to_owned())]);
+    }
+
+    #[test]
+    fn untrimmed() {
+        let text = "  First one?! Dr. Smith said so\n\n\tNext\nparagraph. Last one \t";
+        let actual = split_multi_untrimmed(text, Default::default());
+        let expected =
+            [("  First one?!", " "), ("Dr. Smith said so", "\n\n"), ("\tNext\nparagraph.", " "), ("Last one", " \t")];
+        assert_eq!(actual, expected.map(|(a, b)| (a.to_owned(), b.to_owned())));
+
+        let restored = actual.into_iter().map(|(sentence, separator)| sentence + &separator).collect::<String>();
+        assert_eq!(restored, text);
+
+        let actual = split_multi_untrimmed("One. Two.  ", Default::default());
+        assert_eq!(actual, [("One.".to_owned(), " ".to_owned()), ("Two.".to_owned(), "  ".to_owned())]);
+        assert!(split_multi_untrimmed(" \n ", Default::default()).is_empty());
+    }
+
+    #[test]
+    fn trailing() {
+        let actual = split_multi_with_trailing("One. Two.\n\n", Default::default());
+        assert_eq!(actual.sentences, ["One.", "Two."]);
+        assert_eq!(actual.trailing_whitespace, "\n\n");
+
+        let actual = split_multi_with_trailing("One. Two.", Default::default());
+        assert_eq!(actual.trailing_whitespace, "");
+    }
+}
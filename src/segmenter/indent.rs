@@ -0,0 +1,62 @@
+use super::{split_multi, SegmentConfig};
+
+/// The same as [split_multi], but each sentence is paired with the whitespace that preceded it
+/// in the `text`, which is otherwise trimmed away. Useful to re-indent the output.
+///
+/// The prefix of the first sentence also includes a leading byte order mark, if any.
+/// Joining all the prefixes and sentences restores the `text` without its trailing whitespace,
+/// unless internal newlines are replaced with [InternalNewline::Space](super::InternalNewline::Space).
+pub fn split_multi_with_indent(text: &str, cfg: SegmentConfig) -> Vec<(String, String)> {
+    let bom = if text.starts_with('\u{FEFF}') { '\u{FEFF}'.len_utf8() } else { 0 };
+    let mut cursor = bom;
+    let mut prefix_start = 0;
+    let internal_newline = cfg.internal_newline;
+
+    // the sentences must be verbatim slices of the text to locate them
+    split_multi(&text[bom..], cfg.verbatim())
+        .into_iter()
+        .map(|sentence| {
+            let rest = &text[cursor..];
+            let indent = rest.len() - rest.trim_start().len();
+            let prefix = text[prefix_start..cursor + indent].to_owned();
+            cursor += indent + sentence.len();
+            prefix_start = cursor;
+            (internal_newline.apply(sentence), prefix)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::segmenter::InternalNewline;
+
+    #[test]
+    fn indents() {
+        let text = "  First one. Second one.\n\n\tIndented one.\n    More of it.";
+        let expected =
+            [("First one.", "  "), ("Second one.", " "), ("Indented one.", "\n\n\t"), ("More of it.", "\n    ")];
+        let actual = split_multi_with_indent(text, Default::default());
+        assert_eq!(actual, expected.map(|(a, b)| (a.to_owned(), b.to_owned())));
+
+        let restored = actual.into_iter().map(|(sentence, prefix)| prefix + &sentence).collect::<String>();
+        assert_eq!(restored, text);
+    }
+
+    #[test]
+    fn byte_order_mark() {
+        let text = "\u{FEFF} First one. Second one.";
+        let actual = split_multi_with_indent(text, Default::default());
+        assert_eq!(actual[0], ("First one.".to_owned(), "\u{FEFF} ".to_owned()));
+        assert_eq!(actual[1], ("Second one.".to_owned(), " ".to_owned()));
+    }
+
+    #[test]
+    fn internal_newlines() {
+        let text = " One\n  more. Two.";
+        let cfg = SegmentConfig::default().internal_newline(InternalNewline::Space);
+        let actual = split_multi_with_indent(text, cfg);
+        assert_eq!(actual[0], ("One more.".to_owned(), " ".to_owned()));
+        assert_eq!(actual[1], ("Two.".to_owned(), " ".to_owned()));
+    }
+}
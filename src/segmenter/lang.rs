@@ -0,0 +1,80 @@
+/// A language, to pick its typographic conventions, like the [quote_pairs](Lang::quote_pairs).
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Lang {
+    English,
+    German,
+    French,
+    Japanese,
+    Portuguese,
+}
+
+impl Lang {
+    /// Opening and closing quotes of the language, as used to balance quotes.
+    ///
+    /// Only distinct pairs are listed, so the ASCII `"` and the single `‘’`,
+    /// which doubles as an apostrophe in English, are left out.
+    pub fn quote_pairs(self) -> &'static [(char, char)] {
+        match self {
+            Lang::English => &[('“', '”')],
+            Lang::German => &[('„', '“'), ('‚', '‘'), ('»', '«')],
+            Lang::French => &[('«', '»'), ('‹', '›')],
+            Lang::Japanese => &[('「', '」'), ('『', '』')],
+            Lang::Portuguese => &[('“', '”'), ('«', '»')],
+        }
+    }
+}
+
+/// A writing system with its own punctuation, which is not part of the defaults.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Script {
+    /// The Ge'ez script of Amharic, Tigrinya, and Ge'ez itself.
+    Ethiopic,
+    /// The Tibetan script, whose shad "།" ends clauses and sentences, with or without a space after it.
+    Tibetan,
+    /// The traditional Mongolian script, also used for Manchu.
+    Mongolian,
+}
+
+impl Script {
+    /// Sentence terminals of the script, in addition to the [SENTENCE_TERMINALS](super::SENTENCE_TERMINALS).
+    pub fn terminals(self) -> &'static [char] {
+        match self {
+            // full stop "።" and question mark "፧"
+            Script::Ethiopic => &['\u{1362}', '\u{1367}'],
+            // shad "།", nyis shad "༎", tsheg shad "༏", nyis tsheg shad "༐", rin chen spungs shad "༑", and rgya gram shad "༒"
+            Script::Tibetan => &['\u{0F0D}', '\u{0F0E}', '\u{0F0F}', '\u{0F10}', '\u{0F11}', '\u{0F12}'],
+            // full stop "᠃", and Manchu full stop "᠉"
+            Script::Mongolian => &['\u{1803}', '\u{1809}'],
+        }
+    }
+
+    /// Whether the terminals must be followed by a space to end a sentence, like in most scripts.
+    /// The Tibetan shad is often directly followed by the next syllable, like the CJK full stop "。".
+    pub fn space_after_terminals(self) -> bool {
+        !matches!(self, Script::Tibetan)
+    }
+
+    /// Chars that separate words like spaces do.
+    pub fn word_separators(self) -> &'static [char] {
+        match self {
+            // wordspace "፡"
+            Script::Ethiopic => &['\u{1361}'],
+            // the Tibetan tsheg "་" separates syllables, not words, and Mongolian uses spaces
+            Script::Tibetan | Script::Mongolian => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distinct_pairs() {
+        for lang in [Lang::English, Lang::German, Lang::French, Lang::Japanese, Lang::Portuguese] {
+            assert!(lang.quote_pairs().iter().all(|(opener, closer)| opener != closer));
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use super::{split_multi, SegmentConfig};
+
+/// Assemble sentences from pre-split `lines`, as extracted from a PDF, one line per visual row.
+///
+/// Consecutive lines are joined with a space, and then split like with [split_multi],
+/// so a line break is kept only if a sentence ends there: "Dr." at the end of a line
+/// followed by "Smith" continues the sentence, while "end." followed by "Next" does not.
+/// Empty lines separate paragraphs, which always end sentences.
+///
+/// A line ending with a hyphen after a letter is joined with the next one without a space,
+/// if that starts with a lower-case letter: "catch-" and "up" become "catch-up".
+/// A soft hyphen (U+00AD) is removed in that case: "hyphen\u{AD}" and "ated" become "hyphenated".
+pub fn sentences_from_lines(lines: &[&str], cfg: SegmentConfig) -> Vec<String> {
+    let mut text = String::new();
+    let mut paragraph = false;
+
+    for line in lines.iter().map(|line| line.trim()) {
+        if line.is_empty() {
+            paragraph = !text.is_empty();
+            continue;
+        }
+
+        if paragraph {
+            text.push_str("\n\n");
+            paragraph = false;
+        } else if !text.is_empty() {
+            let hyphenated = line.starts_with(char::is_lowercase)
+                && text.strip_suffix(['-', '\u{2010}', '\u{00AD}']).is_some_and(|s| s.ends_with(char::is_alphabetic));
+
+            if !hyphenated {
+                text.push(' ');
+            } else if text.ends_with('\u{00AD}') {
+                text.pop();
+            }
+        }
+
+        text.push_str(line);
+    }
+
+    split_multi(&text, cfg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mid_sentence_breaks() {
+        let lines = [
+            "This is a sentence",
+            "spanning three",
+            "lines. Then another",
+            "one by Dr.",
+            "Smith.",
+            "",
+            "New paragraph",
+        ];
+        let expected = ["This is a sentence spanning three lines.", "Then another one by Dr. Smith.", "New paragraph"];
+        assert_eq!(sentences_from_lines(&lines, Default::default()), expected);
+    }
+
+    #[test]
+    fn terminal_at_line_end() {
+        let lines = ["It ends here.", "Next one starts", "here."];
+        let expected = ["It ends here.", "Next one starts here."];
+        assert_eq!(sentences_from_lines(&lines, Default::default()), expected);
+    }
+
+    #[test]
+    fn hyphenated() {
+        let lines = ["They will catch-", "up later with the hyphen\u{AD}", "ated words, and B-", "Movies."];
+        let expected = ["They will catch-up later with the hyphenated words, and B- Movies."];
+        assert_eq!(sentences_from_lines(&lines, Default::default()), expected);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(sentences_from_lines(&[], Default::default()).is_empty());
+        assert!(sentences_from_lines(&["", "  "], Default::default()).is_empty());
+    }
+}
@@ -0,0 +1,98 @@
+use std::borrow::Cow;
+use std::sync::LazyLock;
+
+use fancy_regex::Regex;
+
+use super::{SegmentConfig, SegmentError};
+
+/// Inline math of LaTeX, like "$x = 3.14$", "$$a. b$$", or "\(x.y\)", which the
+/// [SegmentConfig::inline_math] and [TokenizeConfig::inline_math](crate::tokenizer::TokenizeConfig::inline_math)
+/// keep in one piece.
+///
+/// As in Pandoc, the opening "$" must be followed, and the closing "$" preceded, by a non-space char,
+/// and the closing "$" must not be followed by a digit, so the prices of "$5 and $10" are not math.
+/// Single "$" spans do not cross lines, and escaped "\$" chars neither open nor close them.
+pub static INLINE_MATH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?xs)
+            (?<! \\ )
+            (?: \$\$ .+? \$\$                                  # display math
+            |   \\\( .+? \\\)                                  # inline math in parentheses
+            |   \$ (?= [^\s$] ) [^$\n]*? (?<= [^\s$\\] ) \$ (?! \d )  # inline math in dollars
+            )
+        "#,
+    )
+    .unwrap()
+});
+
+/// Replace the terminals inside the [INLINE_MATH] of the text with underscores of the same byte length,
+/// so the math spans do not split sentences, while the byte offsets of the text stay the same.
+pub(crate) fn mask_math<'a>(text: &'a str, cfg: &SegmentConfig) -> Result<Cow<'a, str>, SegmentError> {
+    if !cfg.inline_math || !text.contains(['$', '\\']) {
+        return Ok(Cow::Borrowed(text));
+    }
+
+    let mut masked = String::with_capacity(text.len());
+    let mut cursor = 0;
+
+    for math in INLINE_MATH.find_iter(text) {
+        let math = math.map_err(|err| SegmentError::regex(text, err))?;
+        masked.push_str(&text[cursor..math.start()]);
+        for ch in math.as_str().chars() {
+            match ch {
+                ch if cfg.is_terminal(ch) => (0..ch.len_utf8()).for_each(|_| masked.push('_')),
+                ch => masked.push(ch),
+            }
+        }
+        cursor = math.end();
+    }
+
+    masked.push_str(&text[cursor..]);
+    Ok(Cow::Owned(masked))
+}
+
+/// Take the `sentences` of the `masked` text, like of the [mask_math], from the original `text` instead.
+///
+/// The texts must be of the same byte length, and the sentences must be trimmed, verbatim slices of the `masked` text, in order.
+pub(crate) fn unmask(text: &str, masked: &str, sentences: Vec<String>) -> Vec<String> {
+    let mut cursor = 0;
+
+    sentences
+        .into_iter()
+        .map(|sentence| {
+            let rest = &masked[cursor..];
+            let start = cursor + rest.len() - rest.trim_start().len();
+            cursor = start + sentence.len();
+            text[start..cursor].to_owned()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find_all(text: &str) -> Vec<&str> {
+        INLINE_MATH.find_iter(text).map(|m| m.unwrap().as_str()).collect()
+    }
+
+    #[test]
+    fn delimiters() {
+        assert_eq!(
+            find_all("the value $x = 3.14$. Next $y$ and $$a. b$$ or \\(c. d\\)."),
+            ["$x = 3.14$", "$y$", "$$a. b$$", "\\(c. d\\)"]
+        );
+        assert_eq!(find_all("It costs $5 and $10. Or $ 3 $. Or \\$x$."), Vec::<&str>::new());
+        assert_eq!(find_all("Not $a\nb$."), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn masks() {
+        let cfg = SegmentConfig::default().inline_math(true);
+        let text = "Let $x = 3.14!$ and \\(y。\\). Next.";
+        let masked = mask_math(text, &cfg).unwrap();
+        assert_eq!(masked, "Let $x = 3_14_$ and \\(y___\\). Next.");
+        assert_eq!(masked.len(), text.len());
+        assert_eq!(mask_math(text, &SegmentConfig::default()).unwrap(), text);
+    }
+}
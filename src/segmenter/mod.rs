@@ -6,7 +6,9 @@
 //!    skipping one optional, intervening quote and/or bracket.
 //! 2. The next sentence must start with an upper-case letter or a number,
 //!    ignoring one optional quote and/or bracket before it.
-//!    Alternatively, it may start with a camel-cased word, like "gene-A".
+//!    Alternatively, it may start with a camel-cased word, like "mRNA",
+//!    unless [`SegmentConfig::allow_camelcase_start`] is disabled.
+//!    This check can be disabled with [`SegmentConfig::require_capital_start`].
 //! 3. If the sentence ends with a single upper-case letter followed by a dot,
 //!    a split is made (splits names like "A. Dent"), unless there is an easy
 //!    to deduce reason that it is a human name.
@@ -21,23 +23,68 @@
 //!
 //! Sentence splits will always be enforced at **consecutive** line separators.
 //!
-//! Important: Windows text files use `\r\n` as linebreaks and Mac files use `\r`;
-//! Convert the text to Unix linebreaks if the case.
+//! Terminals that are not followed by a space (like in "end.Next") are only split at
+//! if [`SegmentConfig::split_on_missing_space`] is enabled.
+//!
+//! Windows (`\r\n`), Mac (`\r`), and Unicode (`\u{2028}`) linebreaks are taken as Unix newlines (`\n`),
+//! so the sentences of [split_single] and [split_multi] only contain the latter.
 
 mod abbreviations;
+mod batch;
+mod blocks;
+mod boundary;
+mod chunks;
 mod continuations;
+mod dialogue;
+#[cfg(feature = "encoding")]
+mod encoding;
+mod error;
+mod explain;
+mod gaps;
+mod indent;
+mod lang;
+mod lines;
+mod math;
+mod spans;
+mod stats;
+mod terminals;
 mod unix_linebreaks;
 
+use std::borrow::Cow;
 use std::cmp::Ordering;
-use std::sync::LazyLock;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+use std::sync::atomic::{self, AtomicUsize};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
 
+use either::Either;
 use fancy_regex::Regex;
+use itertools::Itertools;
 
 pub use self::abbreviations::*;
+pub use self::batch::*;
+pub use self::blocks::*;
+pub use self::boundary::*;
+pub use self::chunks::*;
 pub use self::continuations::*;
 pub use self::dates::*;
+pub use self::dialogue::*;
+#[cfg(feature = "encoding")]
+pub use self::encoding::*;
+pub use self::error::*;
+pub use self::explain::*;
+pub use self::gaps::*;
+pub use self::indent::*;
+pub use self::lang::*;
+pub use self::lines::*;
+pub use self::math::*;
+pub use self::spans::*;
+pub use self::stats::*;
+pub use self::terminals::*;
 pub use self::unix_linebreaks::*;
-use super::regex::RegexSplitExt;
+use crate::regex::char_class;
+use crate::tokenizer::word_tokenizer;
 
 pub mod dates {
     //! Special facilities to detect European-style dates.
@@ -58,6 +105,18 @@ pub const HYPHENS: &str = r#"\u{00AD}\u{058A}\u{05BE}\u{0F0C}\u{1400}\u{1806}\u{
 pub const SENTENCE_TERMINALS: &str =
     r#".!?\u{203C}\u{203D}\u{2047}\u{2048}\u{2049}\u{3002}\u{FE52}\u{FE57}\u{FF01}\u{FF0E}\u{FF1F}\u{FF61}"#;
 
+/// Right quotes that may follow a sentence terminal, including French guillemets and CJK corner brackets.
+pub const CLOSING_QUOTES: [char; 7] = ['\'', '’', '"', '”', '»', '」', '』'];
+
+/// The chars of [SENTENCE_TERMINALS].
+const SENTENCE_TERMINAL_CHARS: [char; 15] = [
+    '.', '!', '?', '\u{203C}', '\u{203D}', '\u{2047}', '\u{2048}', '\u{2049}', '\u{3002}', '\u{FE52}', '\u{FE57}',
+    '\u{FF01}', '\u{FF0E}', '\u{FF1F}', '\u{FF61}',
+];
+
+/// The horizontal ellipsis "…", a sentence terminal if [SegmentConfig::split_on_ellipsis] is enabled.
+const ELLIPSIS: char = '\u{2026}';
+
 #[deprecated]
 pub const LIST_OF_SENTENCE_TERMINALS: &str =
     ".!?\u{203C}\u{203D}\u{2047}\u{2048}\u{2049}\u{3002}\u{FE52}\u{FE57}\u{FF01}\u{FF0E}\u{FF1F}\u{FF61}";
@@ -85,14 +144,20 @@ pub(crate) fn is_sentence_terminal(ch: char) -> bool {
 
 /// Endings that, if followed by a lower-case word, are not sentence terminals:
 /// - quotations and brackets ("Hello!" said the man.)
-/// - dotted abbreviations (U.S.A. was)
-/// - genus-species-like (m. musculus)
+/// - dotted abbreviations (U.S.A. was, and so on etc. and)
+/// - genus-species-like (m. musculus, E. coli, Staph. aureus, Bacillus sp. strain, Rosa var. alba)
+/// - quoted ellipses ('Well...' she said.)
 pub static BEFORE_LOWER: LazyLock<Regex> = LazyLock::new(|| {
+    let quotes = char_class(CLOSING_QUOTES.into_iter());
     Regex::new(&format!(
         r#"(?uxs)
             (?:
               [{SENTENCE_TERMINALS}] (?: " [)\]]* | [)\]]+ )   # ."]) .") ."  OR  .])  .)
             | \b (?: spp | \p{{L}} \p{{Ll}}? ) \.              # spp.  (species pluralis)  OR  Ll. L.
+            | \b (?: subsp | ssp | var | cv | aff ) \.       # ranks: subspecies, variety, cultivar, affinis
+            | \b etc \.                                     # etc. and
+            | \b St (?: aph | rep ) \.                       # Staph. aureus  Strep. pyogenes
+            | (?: \.{{3}} | \u{{2026}} ) {quotes}+ [)\]]*     # ...'  …”  ...")
             )
             \s+ $
         "#
@@ -104,9 +169,18 @@ pub static BEFORE_LOWER: LazyLock<Regex> = LazyLock::new(|| {
 pub static LOWER_WORD: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(&format!(r#"^\p{{Ll}}+[{HYPHENS}]?\p{{Ll}}*\b"#)).unwrap());
 
+/// Words that start with a lower-case letter, including camel-cased ones like "mRNA" or "iPhone".
+pub static LOWER_START_WORD: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r#"^\p{{Ll}}[\p{{L}}\p{{Nd}}{HYPHENS}]*"#)).unwrap());
+
 /// Upper-case initial after upper-case word at the end of a string.
 pub static MIDDLE_INITIAL_END: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"\b\p{Lu}\p{Ll}+\W+\p{Lu}$"#).unwrap());
 
+/// Abbreviations at the end of a string that precede a number, like the pages of "p. 42" and "pp. 10-12",
+/// or the sections of "para. 3" and "sec. 4", but may end a sentence otherwise.
+pub static BEFORE_NUMBER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\b(?:pp?|[Pp]aras?|[Ss]ecs?|[Ss]ects?)$"#).unwrap());
+
 /// Upper-case word at the beginning of a string.
 pub static UPPER_WORD_START: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^\p{Lu}\p{Ll}+\b"#).unwrap());
 
@@ -121,56 +195,994 @@ pub static UPPER_CASE_END: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"\b[\
 pub static UPPER_CASE_START: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r#"^(?:(?:\(\d{4}\)\s)?[\p{Lu}\p{Lt}]\p{L}*|\d+)[\.,:]\s+"#).unwrap());
 
+/// Enumeration markers of outline items, like "A", "b", "12", or "iv", without the dot.
+pub static OUTLINE_ITEM: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^(?:\p{L}|\d{1,3}|[ivxlc]{1,5}|[IVXLC]{1,5})$"#).unwrap());
+
 /// Sentence end a sentence terminal, followed by spaces.
-/// Optionally, a right quote and any number of closing brackets may succeed the terminal marker.
-/// Alternatively, a yet undefined number of line-breaks also may terminate sentences.
-fn segmenter_regex(line_breaks: usize) -> Regex {
-    Regex::new(&format!(
+/// Optionally, up to two right quotes and any number of closing brackets may succeed the terminal marker.
+/// Alternatively, `line_breaks` or more consecutive newlines also terminate sentences:
+/// [split_single] uses `1`, [split_multi] uses `2`.
+///
+/// Returns the `fancy_regex` source of the pattern, not a compiled object.
+/// Useful for debugging and bug reports.
+pub fn segmenter_pattern(line_breaks: usize, cfg: SegmentConfig) -> String {
+    let terminals = terminals_class(&cfg);
+    let lang_quotes = cfg.balance_quotes.iter().flat_map(|lang| lang.quote_pairs().iter().map(|&(_, closer)| closer));
+    let quotes = char_class(cfg.closing_quotes.iter().copied().chain(lang_quotes));
+    let tabs = if cfg.tab_is_boundary {
+        r#"|                               # Or, if tabs separate units,
+                \s*\t\s*                     #         a tab surrounded by any spaces."#
+    } else {
+        ""
+    };
+    let form_feeds = if cfg.form_feed_is_break {
+        r#"|                               # Or, if form feeds separate sections,
+                \s*\x0C\s*                   #         a form feed surrounded by any spaces."#
+    } else {
+        ""
+    };
+    let bullets = if cfg.treat_bullets_as_boundaries {
+        r#"|                               # Or, if bullets start list items,
+                [^\S\n]*\n\s*                #         a newline with any spaces,
+                (?=[•‣·*-][^\S\n])           #         followed by a bullet and a space."#
+    } else {
+        ""
+    };
+    let pilcrows = if cfg.pilcrow_is_break {
+        r#"|                               # Or, if pilcrows start paragraphs,
+                \s+                         #         spaces, followed by
+                (?=¶\s*\p{Lu})               #         a pilcrow before an upper-case letter."#
+    } else {
+        ""
+    };
+    let emoji = if cfg.emoji_terminal {
+        r#"|                               # Or, if emoji end sentences,
+                (?<=\S) \s*                 #         any spaces after the sentence,
+                (?:                         #         a sequence of emoji, with their
+                  \p{Extended_Pictographic} #         presentation selectors, skin tones, or joined emoji,
+                  (?: \u{FE0F} | \p{Emoji_Modifier} | \u{200D} \p{Extended_Pictographic} )*
+                )+
+                \s+                         #         required spaces, and
+                (?=\p{Lu})                  #         followed by an upper-case letter."#
+    } else {
+        ""
+    };
+    let unspaced = match cfg.script {
+        Some(script) if !script.space_after_terminals() => {
+            let class = char_class(script.terminals().iter().copied().filter(|&ch| cfg.is_terminal(ch)));
+            format!(
+                r#"(?:                         # If the script needs no space after its terminals,
+                  {class}                   #         a run of its terminals, each with
+                  {quotes}{{0,2}}          #         optional right quotes,
+                  [\]\)]*                   #         optional closing brackets and
+                  \s*                       #         optional spaces,
+                )+                          #         ends a sentence first.
+            |"#
+            )
+        }
+        _ => String::new(),
+    };
+    let missing_space = if cfg.split_on_missing_space {
+        format!(
+            r#"|                               # Or, if spaces are allowed to be missing,
+                (?<=\p{{Ll}})                 #         after a lower-case letter,
+                {terminals}                 #         a sentence terminal,
+                {quotes}{{0,2}}            #         optional right quotes and
+                [\]\)]*                     #         optional closing brackets,
+                (?=\p{{Lu}}\p{{Ll}})           #         followed by a capitalized word."#
+        )
+    } else {
+        String::new()
+    };
+
+    format!(
         r#"(?ux)
             (                               # A sentence ends at one of two sequences:
-                [{SENTENCE_TERMINALS}]      # Either, a sequence starting with a sentence terminal,
-                ['’"”]?                     #         an optional right quote,
+            {unspaced}
+                {terminals}                 # Either, a sequence starting with a sentence terminal,
+                {quotes}{{0,2}}            #         optional right quotes,
                 [\]\)]*                     #         optional closing brackets and
                 \s+                         #         a sequence of required spaces.
             |                               # Otherwise,
                 \n{{{line_breaks},}}        #         a sentence also terminates at [consecutive] newlines.
+            {missing_space}
+            {tabs}
+            {form_feeds}
+            {bullets}
+            {pilcrows}
+            {emoji}
             )
         "#
-    ))
-    .unwrap()
+    )
 }
 
 /// A segmentation pattern where any newline char also terminates a sentence.
-pub static DO_NOT_CROSS_LINES: LazyLock<Regex> = LazyLock::new(|| segmenter_regex(1));
+pub static DO_NOT_CROSS_LINES: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&segmenter_pattern(1, Default::default())).unwrap());
 
 /// A segmentation pattern where two or more newline chars also terminate sentences.
-pub static MAY_CROSS_ONE_LINE: LazyLock<Regex> = LazyLock::new(|| segmenter_regex(2));
+pub static MAY_CROSS_ONE_LINE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&segmenter_pattern(2, Default::default())).unwrap());
+
+/// The segmentation patterns of the configs that differ from the static ones, and the other patterns
+/// derived from the configs, by their source, so each is only compiled once per process, and shared by the threads.
+static SEGMENTERS: LazyLock<Mutex<HashMap<String, Arc<Regex>>>> = LazyLock::new(Default::default);
+
+/// The most patterns the [SEGMENTERS] hold, so a program that makes up many configs does not grow it forever.
+const MAX_SEGMENTERS: usize = 256;
+
+/// The segmentation pattern for the `cfg`, which is only compiled if it differs from the static ones,
+/// and was not compiled before.
+fn segmenter(line_breaks: usize, cfg: &SegmentConfig) -> Either<&'static Regex, Arc<Regex>> {
+    let pattern = segmenter_pattern(line_breaks, cfg.clone());
+    if pattern == DO_NOT_CROSS_LINES.as_str() {
+        return Either::Left(&DO_NOT_CROSS_LINES);
+    } else if pattern == MAY_CROSS_ONE_LINE.as_str() {
+        return Either::Left(&MAY_CROSS_ONE_LINE);
+    }
+
+    Either::Right(compiled(pattern))
+}
 
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// The compiled `pattern`, which is taken from the [SEGMENTERS] if it was compiled before.
+fn compiled(pattern: String) -> Arc<Regex> {
+    let mut segmenters = SEGMENTERS.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(regex) = segmenters.get(&pattern) {
+        return Arc::clone(regex);
+    }
+    if segmenters.len() >= MAX_SEGMENTERS {
+        segmenters.clear();
+    }
+
+    let regex = Arc::new(Regex::new(&pattern).unwrap());
+    segmenters.insert(pattern, Arc::clone(&regex));
+    regex
+}
+
+/// A character class of the sentence terminals, with the [SegmentConfig::script] terminals
+/// and without the [SegmentConfig::exclude_terminals].
+///
+/// The ellipsis "…" is a terminal, too, if [SegmentConfig::split_on_ellipsis] is enabled.
+fn terminals_class(cfg: &SegmentConfig) -> String {
+    if cfg.exclude_terminals.is_empty() && cfg.script.is_none() && !cfg.split_on_ellipsis {
+        return format!("[{SENTENCE_TERMINALS}]");
+    }
+
+    let script_terminals = cfg.script.map_or(&[][..], |script| script.terminals());
+    let terminals = SENTENCE_TERMINAL_CHARS.iter().chain(script_terminals).chain([&ELLIPSIS]);
+    char_class(terminals.copied().filter(|&ch| cfg.is_terminal(ch)))
+}
+
+/// The [short_sentence_length](SegmentConfig::short_sentence_length) of the [SegmentConfig::default].
+static DEFAULT_SHORT_SENTENCE_LENGTH: AtomicUsize = AtomicUsize::new(55);
+
+/// The [short_sentence_length](SegmentConfig::short_sentence_length) of the [SegmentConfig::default],
+/// 55 unless it is [set](set_default_short_sentence_length).
+pub fn default_short_sentence_length() -> usize {
+    DEFAULT_SHORT_SENTENCE_LENGTH.load(atomic::Ordering::Relaxed)
+}
+
+/// Set the [short_sentence_length](SegmentConfig::short_sentence_length) of the configs the
+/// [SegmentConfig::default] creates from now on, in all threads, to experiment without passing a config around.
+///
+/// The configs created before keep their length, and a config's own
+/// [short_sentence_length](SegmentConfig::short_sentence_length) still overrides it.
+///
+/// ```rust
+/// use segtok::segmenter::{default_short_sentence_length, set_default_short_sentence_length};
+///
+/// set_default_short_sentence_length(80);
+/// assert_eq!(default_short_sentence_length(), 80);
+/// ```
+pub fn set_default_short_sentence_length(value: usize) {
+    DEFAULT_SHORT_SENTENCE_LENGTH.store(value, atomic::Ordering::Relaxed);
+}
+
+/// The options of the segmentation, built with the consuming setters from the [default](SegmentConfig::default).
+///
+/// Not `Copy` since 0.2, as it holds lists of tokens and abbreviations, but these are shared between
+/// the clones, so a clone is cheap and the config may be passed by value as before.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct SegmentConfig {
     join_on_lowercase: bool,
     /// Length of either sentence fragment inside brackets to assume the fragment is not its own sentence.
     ///
     /// This can be increased/decreased to heighten/lower the likelihood of splits inside brackets.
     short_sentence_length: usize,
+    /// Whether a lower-case word after a terminal may continue the previous sentence.
+    require_capital_start: bool,
+    /// Whether a camel-cased word after a terminal may start a sentence.
+    allow_camelcase_start: bool,
+    /// Whether a terminal directly followed by a capitalized word is a sentence end.
+    split_on_missing_space: bool,
+    /// Whether the ellipsis "…" is a sentence terminal.
+    split_on_ellipsis: bool,
+    /// Tokens that never start a sentence, in addition to the [CONTINUATIONS].
+    no_start_tokens: Arc<[String]>,
+    /// Whether enumeration markers ("A.", "1.", "iv.") start new sentences.
+    outline_items: bool,
+    /// Default [SENTENCE_TERMINALS] that do not end sentences.
+    exclude_terminals: Arc<[char]>,
+    /// Right quotes that may follow a sentence terminal.
+    closing_quotes: Arc<[char]>,
+    /// Whether tabs always split sentences.
+    tab_is_boundary: bool,
+    /// Whether form feeds always split sentences.
+    form_feed_is_break: bool,
+    /// Whether a line starting with a bullet always starts a sentence.
+    treat_bullets_as_boundaries: bool,
+    /// Whether emoji before a capitalized word end sentences.
+    emoji_terminal: bool,
+    /// Whether a pilcrow before a capitalized word always starts a sentence.
+    pilcrow_is_break: bool,
+    /// Whether the "> " quote prefixes of the lines are removed before segmenting.
+    strip_quote_prefix: bool,
+    /// Whether a line of only rule chars, like "-----", is dropped as a section break.
+    rule_line_is_break: bool,
+    /// Whether terminals inside of LaTeX inline math do not split sentences.
+    inline_math: bool,
+    /// Whether two or more spaces after an abbreviation split sentences.
+    double_space_hint: bool,
+    /// Whether a "Name:" label at the start of a line or after a terminal starts a turn of a transcript.
+    speaker_labels: bool,
+    /// The number of tokens below which a [split_multi] sentence is merged into the previous one.
+    min_sentence_tokens: usize,
+    /// What to do with single newlines inside [split_multi] sentences.
+    internal_newline: InternalNewline,
+    /// The language whose quotes are balanced, if any.
+    balance_quotes: Option<Lang>,
+    /// The script whose terminals also end sentences, if any.
+    script: Option<Script>,
+    /// The language whose known abbreviations do not end sentences.
+    language: Lang,
+    /// Abbreviations in addition to the known [ABBREVIATIONS].
+    abbreviations: Arc<AbbreviationList>,
+    /// Case-sensitive abbreviations in addition to the [abbreviations](Self::abbreviations).
+    extra_abbreviations: Arc<AbbreviationList>,
+    /// Whether the sentences must be verbatim slices of the text, see [verbatim](Self::verbatim).
+    verbatim: bool,
+}
+
+/// How [split_multi] returns single newlines inside sentences.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum InternalNewline {
+    /// Keep the newlines and the indentation around them as they are in the text.
+    #[default]
+    Keep,
+    /// Replace each newline, with the indentation around it, by a single space.
+    Space,
+}
+
+impl InternalNewline {
+    /// Apply the mode to a trimmed sentence.
+    fn apply(self, sentence: String) -> String {
+        match self {
+            InternalNewline::Space if sentence.contains('\n') => split_newline(&sentence).collect::<Vec<_>>().join(" "),
+            _ => sentence,
+        }
+    }
 }
 
 impl Default for SegmentConfig {
     fn default() -> Self {
-        Self { join_on_lowercase: false, short_sentence_length: 55 }
+        Self {
+            join_on_lowercase: false,
+            short_sentence_length: default_short_sentence_length(),
+            require_capital_start: true,
+            allow_camelcase_start: true,
+            split_on_missing_space: false,
+            split_on_ellipsis: false,
+            no_start_tokens: Arc::new([]),
+            outline_items: false,
+            exclude_terminals: Arc::new([]),
+            closing_quotes: CLOSING_QUOTES.into(),
+            tab_is_boundary: false,
+            form_feed_is_break: true,
+            treat_bullets_as_boundaries: false,
+            emoji_terminal: false,
+            pilcrow_is_break: false,
+            strip_quote_prefix: false,
+            rule_line_is_break: false,
+            inline_math: false,
+            double_space_hint: false,
+            speaker_labels: false,
+            min_sentence_tokens: 0,
+            internal_newline: InternalNewline::Keep,
+            balance_quotes: None,
+            script: None,
+            language: Lang::English,
+            abbreviations: Arc::default(),
+            extra_abbreviations: Arc::default(),
+            verbatim: false,
+        }
+    }
+}
+
+impl SegmentConfig {
+    /// The length in bytes below which a fragment around an open bracket is joined with its neighbour,
+    /// instead of becoming a sentence of its own; 55 by default, or the [set_default_short_sentence_length].
+    /// See [analyze_brackets] to tune it.
+    pub fn short_sentence_length(mut self, value: usize) -> Self {
+        self.short_sentence_length = value;
+        self
+    }
+
+    /// When disabled, a sentence terminal followed by spaces always starts a new sentence,
+    /// no matter the case of the next character. Useful for all lower-case texts, like transcripts.
+    ///
+    /// Abbreviations and brackets are still taken into account.
+    pub fn require_capital_start(mut self, value: bool) -> Self {
+        self.require_capital_start = value;
+        self
+    }
+
+    /// When disabled, a camel-cased word with a lower-case start, like "mRNA" or "iPhone",
+    /// continues the previous sentence like any other lower-case word, instead of starting a new one:
+    /// "Sales rose in the U.S.A. iPhone sales did, too." is a single sentence.
+    ///
+    /// Has no effect if the [require_capital_start](Self::require_capital_start) check is disabled.
+    pub fn allow_camelcase_start(mut self, value: bool) -> Self {
+        self.allow_camelcase_start = value;
+        self
+    }
+
+    /// When enabled, a terminal right between a lower-case letter and a capitalized word,
+    /// as in "end.Next", is a sentence end, too. Common in OCR or scraped texts.
+    ///
+    /// Decimals ("3.14") and dotted abbreviations ("U.S.A") are not split.
+    pub fn split_on_missing_space(mut self, value: bool) -> Self {
+        self.split_on_missing_space = value;
+        self
+    }
+
+    /// When enabled, the ellipsis "…" ends a sentence like any terminal if a capitalized word follows,
+    /// with or without closing quotes in between, as in "He left… Then silence." or "“He left…” Then silence."
+    ///
+    /// When disabled, by default, only the last dot of "..." is a terminal, as it always is, and "…" is not.
+    /// A quoted ellipsis before a lower-case word never ends a sentence, so "'Well...' she said." is one either way.
+    pub fn split_on_ellipsis(mut self, value: bool) -> Self {
+        self.split_on_ellipsis = value;
+        self
+    }
+
+    /// Tokens that, when found at the very start of a candidate sentence, join it with the previous one,
+    /// regardless of their case. Useful for domain-specific symbols or units, like "mmHg".
+    ///
+    /// A token must be followed by a non-alphanumeric char or the end of the span, so "mL" matches
+    /// "mL of water" but not "mLx". The comparison is case-sensitive.
+    pub fn no_start_tokens<S: Into<String>>(mut self, tokens: impl IntoIterator<Item = S>) -> Self {
+        self.no_start_tokens = tokens.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// When enabled, an enumeration marker ([OUTLINE_ITEM]) followed by a dot,
+    /// right after a sentence terminal, starts a new list item: "A. First point. B. Second point."
+    ///
+    /// Initials inside a sentence, like in "Written by A. Dent", are not affected.
+    /// Neither are initials after known abbreviations, as in "Mr. A. Dent".
+    pub fn outline_items(mut self, value: bool) -> Self {
+        self.outline_items = value;
+        self
+    }
+
+    /// Remove specific chars from the default [SENTENCE_TERMINALS], so the segmenter never splits at them.
+    /// For example, the ideographic full stop "。" in code comments.
+    ///
+    /// Chars that are not sentence terminals are ignored.
+    pub fn exclude_terminals(mut self, terminals: impl IntoIterator<Item = char>) -> Self {
+        self.exclude_terminals = terminals.into_iter().collect();
+        self
+    }
+
+    /// Right quotes that may follow a sentence terminal, up to two of them, as in `."»`.
+    /// Defaults to the [CLOSING_QUOTES].
+    pub fn closing_quotes(mut self, quotes: impl IntoIterator<Item = char>) -> Self {
+        self.closing_quotes = quotes.into_iter().collect();
+        self
+    }
+
+    /// When enabled, a tab always splits sentences, as for fields of tab-separated values.
+    pub fn tab_is_boundary(mut self, value: bool) -> Self {
+        self.tab_is_boundary = value;
+        self
+    }
+
+    /// When enabled (the default), a form feed (`\x0C`) always splits sentences,
+    /// as it usually marks a page or section break.
+    pub fn form_feed_is_break(mut self, value: bool) -> Self {
+        self.form_feed_is_break = value;
+        self
+    }
+
+    /// When enabled, a line starting with a bullet (•, ‣, ·, -, or *) and a space always starts a new sentence,
+    /// even after a single newline and without a terminal, as in "• First item\n• Second item".
+    pub fn treat_bullets_as_boundaries(mut self, value: bool) -> Self {
+        self.treat_bullets_as_boundaries = value;
+        self
+    }
+
+    /// When enabled, emoji followed by spaces and an upper-case letter end a sentence, as in casual text like
+    /// "See you soon 👋 Take care 🙂". The emoji stay with the sentence before them.
+    ///
+    /// Note that an emoji inside a sentence before a name, as in "I ❤️ New York", splits it, too.
+    pub fn emoji_terminal(mut self, value: bool) -> Self {
+        self.emoji_terminal = value;
+        self
+    }
+
+    /// Merge [split_multi] sentences with fewer [word_tokenizer] tokens, punctuation included, into the previous
+    /// sentence, so a stray fragment like "Quickly." in "He left. Quickly. Then he returned." does not stand alone.
+    /// The first sentence of the text and sentences after a paragraph break are kept. Disabled by default (`0`).
+    pub fn min_sentence_tokens(mut self, value: usize) -> Self {
+        self.min_sentence_tokens = value;
+        self
+    }
+
+    /// When enabled, a pilcrow "¶" followed by a capitalized word marks a paragraph, and always starts a sentence,
+    /// as in "Text without a terminal ¶ New paragraph." References like "see ¶ 12" are not affected.
+    pub fn pilcrow_is_break(mut self, value: bool) -> Self {
+        self.pilcrow_is_break = value;
+        self
+    }
+
+    /// When enabled, the quote prefixes of e-mail replies, like "> " or "> > ", are removed from the lines
+    /// before [split_single] and [split_multi] segment them, so "> It was.\n> continued." is "It was.\ncontinued."
+    ///
+    /// The functions that locate the sentences in the text, like [split_multi_spans], keep the prefixes.
+    pub fn strip_quote_prefix(mut self, value: bool) -> Self {
+        self.strip_quote_prefix = value;
+        self
+    }
+
+    /// When enabled, a line of only a repeated rule char (`-`, `=`, `_`, or `*`, at least three times,
+    /// optionally spaced, as in "* * *") is a section break: it is dropped, and the sections around it
+    /// are segmented on their own, so "Section 1\n-------\nSection 2" yields "Section 1" and "Section 2".
+    ///
+    /// The functions that locate the sentences in the text, like [split_multi_spans], keep the rule lines.
+    pub fn rule_line_is_break(mut self, value: bool) -> Self {
+        self.rule_line_is_break = value;
+        self
+    }
+
+    /// When enabled, LaTeX inline math, like "$x = 3.14$" or "\\(a.b\\)", never splits sentences,
+    /// so "The value $x = 3.14$. Next." yields "The value $x = 3.14$." and "Next.". See [INLINE_MATH].
+    pub fn inline_math(mut self, value: bool) -> Self {
+        self.inline_math = value;
+        self
+    }
+
+    /// When enabled, two or more spaces (but no newline) after a terminal are taken as the typewriter
+    /// convention for a sentence end, and break the tie where the terminal could also end an abbreviation
+    /// or an initial, so "He lives in the U.S.  Then he left." yields two sentences.
+    ///
+    /// A single space keeps the abbreviation joined, as do the other heuristics, like a lower-case word after it.
+    pub fn double_space_hint(mut self, value: bool) -> Self {
+        self.double_space_hint = value;
+        self
+    }
+
+    /// When enabled, a speaker label of a transcript, like "Alice:" or "Dr. Smith:", at the start of a line
+    /// or after a terminal starts a turn, and the turns are segmented on their own, so "Alice: Hello. Bob: Hi
+    /// there." yields "Alice: Hello." and "Bob: Hi there.", even where "Bob" could follow an abbreviation.
+    ///
+    /// The label must be of capitalized words, so the colons of times and ratios, like "10:30", are kept.
+    /// See [SPEAKER_LABEL] and [speaker_label].
+    pub fn speaker_labels(mut self, value: bool) -> Self {
+        self.speaker_labels = value;
+        self
+    }
+
+    /// Whether [split_multi] keeps single newlines inside sentences (the default),
+    /// or replaces them with spaces, as in "This is a\nmultiline sentence."
+    pub fn internal_newline(mut self, value: InternalNewline) -> Self {
+        self.internal_newline = value;
+        self
+    }
+
+    /// When set, terminals inside the [quote pairs](Lang::quote_pairs) of the language do not split sentences,
+    /// so „Geh. Jetzt!“ stays one sentence in German. The closing quotes may also follow a terminal.
+    ///
+    /// An unclosed quote joins sentences up to the next paragraph break.
+    pub fn balance_quotes(mut self, lang: Option<Lang>) -> Self {
+        self.balance_quotes = lang;
+        self
+    }
+
+    /// When set, the [terminals](Script::terminals) of the script also end sentences,
+    /// like the Ethiopic full stop "።" in Amharic.
+    pub fn script(mut self, script: Option<Script>) -> Self {
+        self.script = script;
+        self
+    }
+
+    /// The language of the text, whose [known abbreviations](abbreviations_for) do not end sentences,
+    /// like "pág" in Portuguese. The default English also covers the common German abbreviations.
+    pub fn language(mut self, lang: Lang) -> Self {
+        self.language = lang;
+        self
+    }
+
+    /// Abbreviations that do not end sentences, in addition to the known [ABBREVIATIONS],
+    /// like "Ref" in "See Ref. 3 for details."
+    ///
+    /// They are matched at the end of a candidate sentence, after a word boundary and before its dot.
+    /// The list is built once, so the config may be cloned and reused without rebuilding it.
+    /// It replaces the list of an earlier call, but keeps the [extra_abbreviations](Self::extra_abbreviations).
+    pub fn abbreviations(mut self, list: AbbreviationList) -> Self {
+        self.abbreviations = Arc::new(list);
+        self
+    }
+
+    /// Case-sensitive abbreviations, without their dots, like `["Fig", "Tab", "Ref", "Sec"]`,
+    /// in addition to the [abbreviations](Self::abbreviations) list and to the words of the earlier calls.
+    pub fn extra_abbreviations<S: AsRef<str>>(mut self, words: impl IntoIterator<Item = S>) -> Self {
+        Arc::make_mut(&mut self.extra_abbreviations).extend(words);
+        self
+    }
+
+    /// Check if the char is a sentence terminal, with the script terminals and without the excluded ones.
+    pub(crate) fn is_terminal(&self, ch: char) -> bool {
+        (SENTENCE_TERMINAL_CHARS.contains(&ch)
+            || self.script.is_some_and(|script| script.terminals().contains(&ch))
+            || self.split_on_ellipsis && ch == ELLIPSIS)
+            && !self.exclude_terminals.contains(&ch)
+    }
+
+    /// Disable the options that rewrite the text before segmenting it,
+    /// for the functions that need the sentences to be verbatim slices of the text.
+    ///
+    /// The non-Unix linebreaks are still taken as newlines, but the sentences keep them as they are.
+    pub(crate) fn verbatim(self) -> Self {
+        let cfg = self.internal_newline(InternalNewline::Keep).strip_quote_prefix(false).rule_line_is_break(false);
+        Self { verbatim: true, ..cfg }
+    }
+
+    /// Check if the char is a bracket or quote that may close a sentence after its terminal.
+    pub(crate) fn is_closing(&self, ch: char) -> bool {
+        let lang_quotes = self.balance_quotes.map_or(&[][..], |lang| lang.quote_pairs());
+        matches!(ch, ')' | ']') || self.closing_quotes.contains(&ch) || lang_quotes.iter().any(|&(_, q)| q == ch)
+    }
+
+    /// Check that the config is usable, so a malformed entry surfaces at startup
+    /// instead of a panic in the middle of segmentation.
+    ///
+    /// Compiles the derived segmentation patterns and checks the [no_start_tokens](Self::no_start_tokens).
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(token) = self.no_start_tokens.iter().find(|t| t.is_empty() || t.trim() != t.as_str()) {
+            return Err(ConfigError::NoStartToken { token: token.clone() });
+        }
+
+        for line_breaks in [1, 2] {
+            Regex::new(&segmenter_pattern(line_breaks, self.clone()))
+                .map_err(|err| ConfigError::Pattern { line_breaks, message: err.to_string() })?;
+        }
+
+        Ok(())
     }
 }
 
 /// Default: split `text` at sentence terminals and at newline chars.
+/// The lines are trimmed, and blank ones are dropped.
+///
+/// Panics if a pattern fails on the text, see [try_split_single].
 pub fn split_single(text: &str, cfg: SegmentConfig) -> Vec<String> {
-    let sentences = sentences(DO_NOT_CROSS_LINES.split_with_separators(text), cfg);
-    sentences.iter().flat_map(|sentence| sentence.split("\n").map(ToOwned::to_owned)).collect()
+    try_split_single(text, cfg).unwrap()
+}
+
+/// The [split_single], but returns a [SegmentError::Regex] if a pattern fails on a span of the text,
+/// like when it overflows the stack, instead of panicking.
+///
+/// A heuristic whose pattern exceeds the backtrack limit on a span, like on a very long word,
+/// does not apply, so the span is split.
+pub fn try_split_single(text: &str, cfg: SegmentConfig) -> Result<Vec<String>, SegmentError> {
+    try_split_single_with(text, cfg, None)
+}
+
+/// The [split_single], but the `is_start` closure decides if a span after a split starts a sentence,
+/// like with [split_multi_with].
+///
+/// ```rust
+/// use segtok::segmenter::{split_single_with, SegmentConfig};
+///
+/// let sentences = split_single_with("Run it. then stop.\nDone.", SegmentConfig::default(), |_| true);
+/// assert_eq!(sentences, ["Run it.", "then stop.", "Done."]);
+/// ```
+pub fn split_single_with(text: &str, cfg: SegmentConfig, is_start: impl Fn(&str) -> bool) -> Vec<String> {
+    try_split_single_with(text, cfg, Some(&is_start)).unwrap()
+}
+
+fn try_split_single_with(
+    text: &str,
+    cfg: SegmentConfig,
+    is_start: Option<&dyn Fn(&str) -> bool>,
+) -> Result<Vec<String>, SegmentError> {
+    if let Cow::Owned(normalized) = normalize_linebreaks(text) {
+        let sentences = try_split_single_with(&normalized, cfg.clone(), is_start)?;
+        if !cfg.verbatim {
+            return Ok(sentences);
+        }
+
+        let mut offsets = LinebreakOffsets::new(text);
+        return Ok(sentences.iter().map(|sentence| offsets.take(text, sentence).to_owned()).collect());
+    }
+    if cfg.rule_line_is_break && text.contains(RULE_CHARS) {
+        let cfg = cfg.rule_line_is_break(false);
+        let sections =
+            rule_sections(text).into_iter().map(|section| try_split_single_with(section, cfg.clone(), is_start));
+        return sections.flatten_ok().collect();
+    }
+    if cfg.speaker_labels && text.contains(':') {
+        let cfg = cfg.speaker_labels(false);
+        let turns =
+            speaker_turns(text, &cfg)?.into_iter().map(|turn| try_split_single_with(turn, cfg.clone(), is_start));
+        return turns.flatten_ok().collect();
+    }
+
+    let text = &*strip_quote_prefixes(text, &cfg);
+    let masked = mask_math(text, &cfg)?;
+    let mut sentences = {
+        let is_start = is_start.map(|is_start| unmasked(is_start, text, &masked));
+        sentences(&masked, 1, &cfg, is_start.as_ref().map(|is_start| is_start as _))?
+    };
+    if let Cow::Owned(masked) = masked {
+        sentences = unmask(text, &masked, sentences);
+    }
+    Ok(sentences.iter().flat_map(|sentence| split_newline(sentence).map(ToOwned::to_owned)).collect())
 }
 
 /// Sentences may contain non-consecutive (single) newline chars,
 /// while consecutive newline chars ("paragraph separators") always split sentences.
+/// A run of terminals, like "?! ?!", ends a single sentence, and no sentence is empty.
+///
+/// Panics if a pattern fails on the text, see [try_split_multi].
 pub fn split_multi(text: &str, cfg: SegmentConfig) -> Vec<String> {
-    sentences(MAY_CROSS_ONE_LINE.split_with_separators(text), cfg)
+    try_split_multi(text, cfg).unwrap()
+}
+
+/// The [split_multi], but returns a [SegmentError::Regex] if a pattern fails on a span of the text,
+/// like when it overflows the stack, instead of panicking.
+///
+/// A heuristic whose pattern exceeds the backtrack limit on a span, like on a very long word,
+/// does not apply, so the span is split.
+///
+/// ```rust
+/// use segtok::segmenter::{try_split_multi, SegmentConfig};
+///
+/// assert_eq!(try_split_multi("One. Two.", SegmentConfig::default()).unwrap(), ["One.", "Two."]);
+/// ```
+pub fn try_split_multi(text: &str, cfg: SegmentConfig) -> Result<Vec<String>, SegmentError> {
+    try_split_multi_with(text, cfg, None)
+}
+
+/// The [split_multi], but the `is_start` closure decides if the span after a sentence terminal starts a sentence,
+/// in place of the case rules of the [require_capital_start](SegmentConfig::require_capital_start),
+/// so it can override them for the languages and domains they don't cover.
+///
+/// A span it rejects is joined to the sentence before it, and a span it accepts is split from it,
+/// unless another rule joins them, like an abbreviation, a [no_start_tokens](SegmentConfig::no_start_tokens),
+/// or an open bracket. A paragraph break always splits.
+///
+/// ```rust
+/// use segtok::segmenter::{split_multi_with, SegmentConfig};
+///
+/// let text = "Run it. then stop. iPhone sales. Done.";
+/// let sentences = split_multi_with(text, SegmentConfig::default(), |span| !span.starts_with("then"));
+/// assert_eq!(sentences, ["Run it. then stop.", "iPhone sales.", "Done."]);
+/// ```
+pub fn split_multi_with(text: &str, cfg: SegmentConfig, is_start: impl Fn(&str) -> bool) -> Vec<String> {
+    try_split_multi_with(text, cfg, Some(&is_start)).unwrap()
+}
+
+fn try_split_multi_with(
+    text: &str,
+    cfg: SegmentConfig,
+    is_start: Option<&dyn Fn(&str) -> bool>,
+) -> Result<Vec<String>, SegmentError> {
+    try_split_multi_iter(Cow::Borrowed(text), cfg, is_start, Hooks::default())
+        .map_ok(|(sentence, _)| sentence)
+        .collect()
+}
+
+/// A callback of every merge with the text that caused it and the byte offset of the span it joined in the text.
+type OnMerge<'a> = Rc<dyn Fn(MergeKind, &str, usize) + 'a>;
+
+/// The callbacks of [try_split_multi_iter], which report how it joined the spans into sentences.
+#[derive(Clone)]
+struct Hooks<'a> {
+    on_merge: OnMerge<'a>,
+    /// The length of the shorter fragment whenever the bracket heuristic decides a join by comparing it
+    /// with the [short_sentence_length](SegmentConfig::short_sentence_length).
+    on_bracket_fragment: Rc<dyn Fn(usize) + 'a>,
+}
+
+impl Default for Hooks<'_> {
+    fn default() -> Self {
+        Self { on_merge: Rc::new(|_, _, _| {}), on_bracket_fragment: Rc::new(|_| {}) }
+    }
+}
+
+impl<'a> Hooks<'a> {
+    /// The hooks of a text derived from the text, like a section of it,
+    /// whose offsets are mapped back to the text by the `original` closure.
+    fn mapped(&self, original: impl Fn(usize) -> usize + 'a) -> Self {
+        let on_merge = Rc::clone(&self.on_merge);
+        let on_merge = Rc::new(move |kind, merged: &str, offset| on_merge(kind, merged, original(offset)));
+        Self { on_merge, on_bracket_fragment: Rc::clone(&self.on_bracket_fragment) }
+    }
+}
+
+/// The sentences of [try_split_multi_with], segmented lazily, with the texts of the sections and of
+/// the speaker turns owned if the `text` is, as after the normalization of its linebreaks.
+///
+/// Each sentence tells if a paragraph break or a rule line separates it from the one before,
+/// and the merges of the spans are reported to the `hooks`, with their offsets in the `text`.
+fn try_split_multi_iter<'a>(
+    text: Cow<'a, str>,
+    cfg: SegmentConfig,
+    is_start: Option<&'a dyn Fn(&str) -> bool>,
+    hooks: Hooks<'a>,
+) -> Box<dyn Iterator<Item = Result<(String, bool), SegmentError>> + 'a> {
+    if let Cow::Owned(normalized) = normalize_linebreaks(&text) {
+        let offsets = LinebreakOffsets::new(&text);
+        let hooks = hooks.mapped(move |offset| offsets.original(offset));
+        let sentences = try_split_multi_iter(Cow::Owned(normalized), cfg.clone(), is_start, hooks);
+        if !cfg.verbatim {
+            return sentences;
+        }
+
+        let mut offsets = LinebreakOffsets::new(&text);
+        return Box::new(
+            sentences.map_ok(move |(sentence, paragraph)| (offsets.take(&text, &sentence).to_owned(), paragraph)),
+        );
+    }
+    if cfg.rule_line_is_break && text.contains(RULE_CHARS) {
+        let cfg = cfg.rule_line_is_break(false);
+        let sections = subtexts(&text, rule_sections(&text));
+        let starts = sections.iter().map(|section| section.start).collect::<Vec<_>>();
+        let sections = owned_subtexts(text, sections).zip(starts).enumerate();
+        return Box::new(sections.flat_map(move |(idx, (section, start))| {
+            let hooks = hooks.mapped(move |offset| start + offset);
+            after_break(try_split_multi_iter(section, cfg.clone(), is_start, hooks), idx > 0)
+        }));
+    }
+    if cfg.speaker_labels && text.contains(':') {
+        let cfg = cfg.speaker_labels(false);
+        let turns = match speaker_turns(&text, &cfg) {
+            Ok(turns) => subtexts(&text, turns),
+            Err(err) => return Box::new(std::iter::once(Err(err))),
+        };
+        // the whitespace around the start of a turn may be split between the turns
+        let breaks = turns
+            .iter()
+            .map(|turn| {
+                let (before, after) = text.split_at(turn.start);
+                let separator = &text[before.trim_end().len()..turn.start + after.len() - after.trim_start().len()];
+                separator.contains("\n\n")
+            })
+            .collect::<Vec<_>>();
+        let starts = turns.iter().map(|turn| turn.start).collect::<Vec<_>>();
+        let turns = owned_subtexts(text, turns).zip(starts).zip(breaks);
+        return Box::new(turns.flat_map(move |((turn, start), paragraph)| {
+            let hooks = hooks.mapped(move |offset| start + offset);
+            after_break(try_split_multi_iter(turn, cfg.clone(), is_start, hooks), paragraph)
+        }));
+    }
+
+    let (text, hooks) = match strip_quote_prefixes(&text, &cfg) {
+        Cow::Owned(stripped) => {
+            let lines = line_offsets(&text, &stripped);
+            let hooks = hooks.mapped(move |offset| original_offset(&lines, offset));
+            (Cow::Owned(stripped), hooks)
+        }
+        Cow::Borrowed(_) => (text, hooks),
+    };
+    let masked = match mask_math(&text, &cfg) {
+        Ok(Cow::Owned(masked)) => Some(masked),
+        Ok(Cow::Borrowed(_)) => None,
+        Err(err) => return Box::new(std::iter::once(Err(err))),
+    };
+    let sentences = Sentences::new(2, &cfg);
+    Box::new(MultiSentences { text, masked, cfg, is_start, hooks, sentences, short: None, prev_end: 0 })
+}
+
+/// Mark the first of the `sentences` of a section as separated by a paragraph break, if the `paragraph` is.
+fn after_break<'a>(
+    sentences: impl Iterator<Item = Result<(String, bool), SegmentError>> + 'a,
+    paragraph: bool,
+) -> impl Iterator<Item = Result<(String, bool), SegmentError>> + 'a {
+    sentences
+        .enumerate()
+        .map(move |(idx, sentence)| sentence.map(|(text, after)| (text, after || idx == 0 && paragraph)))
+}
+
+/// The byte ranges of the `parts` of the `text`, like its [rule_sections].
+fn subtexts(text: &str, parts: Vec<&str>) -> Vec<Range<usize>> {
+    let offset = |part: &str| part.as_ptr() as usize - text.as_ptr() as usize;
+    parts.into_iter().map(|part| offset(part)..offset(part) + part.len()).collect()
+}
+
+/// The texts of the `ranges` of the `text`, which are owned if the `text` is.
+fn owned_subtexts<'a>(text: Cow<'a, str>, ranges: Vec<Range<usize>>) -> impl Iterator<Item = Cow<'a, str>> {
+    match text {
+        Cow::Borrowed(text) => Either::Left(ranges.into_iter().map(move |range| Cow::Borrowed(&text[range]))),
+        Cow::Owned(text) => Either::Right(ranges.into_iter().map(move |range| Cow::Owned(text[range].to_owned()))),
+    }
+}
+
+/// The sentences of a text without rule lines or speaker turns, as of [try_split_multi_with],
+/// which are merged by the [min_sentence_tokens](SegmentConfig::min_sentence_tokens) rule
+/// as they are joined, so only its pending sentence is held.
+struct MultiSentences<'a> {
+    text: Cow<'a, str>,
+    /// The text with its [mask_math], if it has any math, which the sentences are segmented on.
+    masked: Option<String>,
+    cfg: SegmentConfig,
+    is_start: Option<&'a dyn Fn(&str) -> bool>,
+    hooks: Hooks<'a>,
+    sentences: Sentences,
+    /// The pending sentence of the [min_sentence_tokens](SegmentConfig::min_sentence_tokens) rule.
+    short: Option<Range<usize>>,
+    /// The end of the last sentence, to tell the paragraph breaks before the next one.
+    prev_end: usize,
+}
+
+impl Iterator for MultiSentences<'_> {
+    type Item = Result<(String, bool), SegmentError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { text, masked, cfg, is_start, hooks, sentences, short, prev_end } = self;
+        let masked = masked.as_deref().unwrap_or(text);
+
+        let is_start = is_start.map(|is_start| unmasked(is_start, text, masked));
+        let is_start = is_start.as_ref().map(|is_start| is_start as _);
+        let mut on_merge = |kind, merged: &str, offset| (hooks.on_merge)(kind, merged, offset);
+        let mut on_bracket_fragment = |len| (hooks.on_bracket_fragment)(len);
+        let mut next_sentence =
+            || sentences.next_sentence(masked, cfg, is_start, &mut on_bracket_fragment, &mut on_merge);
+        let is_short =
+            |sentence: &str| cfg.min_sentence_tokens > 0 && word_tokenizer(sentence).len() < cfg.min_sentence_tokens;
+        let sentence = merge_pending(short, masked, &mut next_sentence, is_short);
+
+        sentence.transpose().map(|sentence| {
+            let sentence = sentence?;
+            let paragraph = text[*prev_end..sentence.start].contains("\n\n");
+            *prev_end = sentence.end;
+            Ok((cfg.internal_newline.apply(text[sentence].to_owned()), paragraph))
+        })
+    }
+}
+
+/// The `is_start` closure for the spans of the `masked` text, like of the [mask_math],
+/// which is asked about the same bytes of the original `text` instead.
+fn unmasked<'a>(is_start: &'a dyn Fn(&str) -> bool, text: &'a str, masked: &'a str) -> impl Fn(&str) -> bool + 'a {
+    move |span| {
+        let start = span.as_ptr() as usize - masked.as_ptr() as usize;
+        is_start(&text[start..start + span.len()])
+    }
+}
+
+/// Pull the sentences of the text until one is not merged into the `pending` one by the `should_merge` check,
+/// like the ones with fewer than the [min_sentence_tokens](SegmentConfig::min_sentence_tokens),
+/// and return the pending one, or the last one once there are no more.
+///
+/// A sentence is merged together with the whitespace before it, unless a paragraph break separates them.
+fn merge_pending(
+    pending: &mut Option<Range<usize>>,
+    text: &str,
+    next_sentence: &mut dyn FnMut() -> Result<Option<Range<usize>>, SegmentError>,
+    should_merge: impl Fn(&str) -> bool,
+) -> Result<Option<Range<usize>>, SegmentError> {
+    while let Some(sentence) = next_sentence()? {
+        match pending {
+            Some(last) if !text[last.end..sentence.start].contains("\n\n") && should_merge(&text[sentence.clone()]) => {
+                last.end = sentence.end;
+            }
+            _ => {
+                if let Some(last) = pending.replace(sentence) {
+                    return Ok(Some(last));
+                }
+            }
+        }
+    }
+
+    Ok(pending.take())
+}
+
+/// The [split_multi] sentences paired with their index, so they can be reordered, as by a score, and restored.
+///
+/// ```rust
+/// use segtok::segmenter::split_multi_enumerated;
+///
+/// let sentences = split_multi_enumerated("One. Two.", Default::default());
+/// assert_eq!(sentences, [(0, "One.".to_owned()), (1, "Two.".to_owned())]);
+/// ```
+pub fn split_multi_enumerated(text: &str, cfg: SegmentConfig) -> Vec<(usize, String)> {
+    split_multi(text, cfg).into_iter().enumerate().collect()
+}
+
+/// The [split_multi] sentences, but segmented lazily, so the sentences of a long document
+/// are not collected before the first one is seen.
+///
+/// Only the sentence pending a join with the next span is held, so the sentences are the same as of [split_multi].
+///
+/// Panics if a pattern fails on the text, like [split_multi].
+///
+/// ```rust
+/// use segtok::segmenter::split_multi_iter;
+///
+/// let mut sentences = split_multi_iter("One. Two.\n\nThree.", Default::default());
+/// assert_eq!(sentences.next().as_deref(), Some("One."));
+/// assert_eq!(sentences.collect::<Vec<_>>(), ["Two.", "Three."]);
+/// ```
+pub fn split_multi_iter(text: &str, cfg: SegmentConfig) -> impl Iterator<Item = String> + '_ {
+    try_split_multi_iter(Cow::Borrowed(text), cfg, None, Hooks::default()).map(|sentence| sentence.unwrap().0)
+}
+
+/// Remove the quote prefixes of the lines, if the [strip_quote_prefix](SegmentConfig::strip_quote_prefix) is enabled.
+fn strip_quote_prefixes<'a>(text: &'a str, cfg: &SegmentConfig) -> Cow<'a, str> {
+    if !cfg.strip_quote_prefix || !text.contains('>') {
+        return Cow::Borrowed(text);
+    }
+
+    let strip = |line: &'a str| match line.trim_start_matches([' ', '\t']) {
+        quoted if quoted.starts_with('>') => quoted.trim_start_matches(['>', ' ', '\t']),
+        _ => line,
+    };
+
+    Cow::Owned(text.split_inclusive('\n').map(strip).collect())
+}
+
+/// The offsets of the starts of the lines of the `stripped` text, in it and in the `text`,
+/// whose lines only lost a prefix, like to the [strip_quote_prefixes].
+fn line_offsets(text: &str, stripped: &str) -> Vec<(usize, usize)> {
+    let (mut offset, mut original) = (0, 0);
+    let lines = text.split_inclusive('\n').zip(stripped.split_inclusive('\n'));
+    lines
+        .map(|(line, stripped)| {
+            let start = (offset, original + line.len() - stripped.len());
+            (offset, original) = (offset + stripped.len(), original + line.len());
+            start
+        })
+        .collect()
+}
+
+/// The offset in the original text of an `offset` in a text rewritten piece by piece,
+/// given the `pieces` starts in both texts, in ascending order.
+fn original_offset(pieces: &[(usize, usize)], offset: usize) -> usize {
+    match pieces.partition_point(|&(start, _)| start <= offset) {
+        0 => offset,
+        idx => pieces[idx - 1].1 + offset - pieces[idx - 1].0,
+    }
+}
+
+/// Split the text into the sections between the rule lines, without the rule lines.
+fn rule_sections(text: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = 0;
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        if is_rule_line(line) {
+            sections.push(&text[start..offset]);
+            start = offset + line.len();
+        }
+        offset += line.len();
+    }
+
+    sections.push(&text[start..]);
+    sections
+}
+
+/// The chars of horizontal rules, like "-----" or "* * *".
+const RULE_CHARS: [char; 4] = ['-', '=', '_', '*'];
+
+/// Check if the line is a horizontal rule, made of at least three of the same [RULE_CHARS] and whitespace.
+fn is_rule_line(line: &str) -> bool {
+    let mut chars = line.chars().filter(|ch| !ch.is_whitespace());
+    let Some(rule) = chars.next().filter(|ch| RULE_CHARS.contains(ch)) else { return false };
+    let (count, same) = chars.fold((1, true), |(count, same), ch| (count + 1, same && ch == rule));
+    same && count >= 3
 }
 
 /// Split the `text` at newlines (``\\n'') and strip the lines,
@@ -179,128 +1191,434 @@ pub fn split_newline(text: &str) -> impl Iterator<Item = &str> {
     text.split('\n').map(str::trim).filter(|&s| !s.is_empty())
 }
 
-/// Join spans back together into sentences as necessary.
-fn sentences<'a>(spans: impl Iterator<Item = &'a str>, cfg: SegmentConfig) -> Vec<String> {
-    let shorter_than_a_typical_sentence = |x: usize, y: usize| x.min(y) < cfg.short_sentence_length;
+/// Join spans back together into sentences as necessary, where the `is_start` closure, if any,
+/// decides on the span after a terminal in place of the case rules.
+fn sentences(
+    text: &str,
+    line_breaks: usize,
+    cfg: &SegmentConfig,
+    is_start: Option<&dyn Fn(&str) -> bool>,
+) -> Result<Vec<String>, SegmentError> {
+    let mut sentences = Sentences::new(line_breaks, cfg);
+    let mut res = Vec::new();
+    while let Some(sentence) = sentences.next_sentence(text, cfg, is_start, &mut |_| {}, &mut |_, _, _| {})? {
+        res.push(text[sentence].to_owned());
+    }
+    Ok(res)
+}
+
+/// The spans of a text, split at the [segmenter] pattern, joined into sentences one at a time.
+///
+/// Only the span after the current one, and the pending sentence `last` are held, so the text can be
+/// segmented lazily. The text is passed to each step, and the sentences are trimmed byte ranges of it.
+struct Sentences {
+    segmenter: Either<&'static Regex, Arc<Regex>>,
+    /// The end of the last match of the segmenter.
+    match_end: usize,
+    /// The match after the non-match span last taken.
+    next_match: Option<Range<usize>>,
+    /// The span after the current one, if it was looked at.
+    lookahead: Option<Range<usize>>,
+    /// The index of the current span, whose parity tells the markers (odd) from the other spans (even).
+    pos: usize,
+    prev: Range<usize>,
+    /// The index and the start of the first span that is joined with the abbreviations after it.
+    from: Option<(usize, usize)>,
+    _last: Option<Range<usize>>,
+    /// The nesting of the brackets, then of the quotes, which is updated with each span joined to `last`,
+    /// so a long run of joined spans is not rescanned.
+    nesting: Vec<Nesting>,
+}
+
+impl Sentences {
+    fn new(line_breaks: usize, cfg: &SegmentConfig) -> Self {
+        let quote_pairs = cfg.balance_quotes.map_or(&[][..], Lang::quote_pairs);
+        let nesting = [('(', ')'), ('[', ']')].iter().chain(quote_pairs).map(|&pair| Nesting::new(pair)).collect();
+
+        Self {
+            segmenter: segmenter(line_breaks, cfg),
+            match_end: 0,
+            next_match: None,
+            lookahead: None,
+            pos: 0,
+            prev: 0..0,
+            from: None,
+            _last: None,
+            nesting,
+        }
+    }
+
+    /// The next sentence of the text, or `None` if there are no more.
+    fn next_sentence(
+        &mut self,
+        text: &str,
+        cfg: &SegmentConfig,
+        is_start: Option<&dyn Fn(&str) -> bool>,
+        on_bracket_fragment: &mut dyn FnMut(usize),
+        on_merge: &mut dyn FnMut(MergeKind, &str, usize),
+    ) -> Result<Option<Range<usize>>, SegmentError> {
+        let mut shorter_than_a_typical_sentence = |x: usize, y: usize| {
+            on_bracket_fragment(x.min(y));
+            x.min(y) < cfg.short_sentence_length
+        };
+
+        while let Some(current) = self.join_abbreviations(text, cfg, is_start.is_none(), on_merge)? {
+            let Some(last) = self._last.clone() else {
+                self.nesting.iter_mut().for_each(|n| n.push(&text[current.clone()]));
+                self._last = Some(current);
+                continue;
+            };
+
+            let merge = merge_kind(
+                &text[last.clone()],
+                &text[current.clone()],
+                &self.nesting,
+                cfg,
+                is_start,
+                &mut shorter_than_a_typical_sentence,
+            )?;
+            let join = merge.is_some();
+            if let Some((kind, merged)) = merge {
+                on_merge(kind, &merged, current.start);
+            } else {
+                self.nesting.iter_mut().for_each(Nesting::clear);
+            }
+            self.nesting.iter_mut().for_each(|n| n.push(&text[current.clone()]));
 
-    let mut _last: Option<String> = None;
-    let spans = spans.collect::<Vec<_>>();
-    let mut res = Vec::with_capacity(spans.len());
+            if join {
+                self._last = Some(last.start..current.end);
+            } else {
+                self._last = Some(current);
+                let sentence = trim_range(text, last);
+                if !sentence.is_empty() {
+                    return Ok(Some(sentence));
+                }
+            }
+        }
 
-    for current in join_abbreviations(&spans) {
-        match _last {
+        Ok(self._last.take().map(|last| trim_range(text, last)).filter(|sentence| !sentence.is_empty()))
+    }
+
+    /// Join the spans that match the `ABBREVIATIONS` pattern, and return the next run of joined spans.
+    ///
+    /// A lone lower-case word after a terminal is only joined with the `case_rules`.
+    fn join_abbreviations(
+        &mut self,
+        text: &str,
+        cfg: &SegmentConfig,
+        case_rules: bool,
+        on_merge: &mut dyn FnMut(MergeKind, &str, usize),
+    ) -> Result<Option<Range<usize>>, SegmentError> {
+        fn ends_with_whitespace(str: &str) -> bool {
+            str.bytes().next_back().is_some_and(|ch| ch.is_ascii_whitespace())
+        }
+
+        while let Some(span) = self.next_span(text)? {
+            let pos = self.pos;
+            self.pos += 1;
+
+            if pos.is_multiple_of(2) {
+                self.from = self.from.or(Some((pos, span.start)));
+                self.prev = span;
+                continue;
+            }
+
+            let prev = &text[self.prev.clone()];
+            let marker = &text[span.clone()];
+            let next = self.peek_span(text)?.map(|next| &text[next]);
+            let is_item = |span: &str| -> Result<bool, SegmentError> {
+                Ok(cfg.outline_items && marker.starts_with('.') && matches_or_false(&OUTLINE_ITEM, span)?)
+            };
+            // digits after a joined abbreviation belong to it ("No. 1."), so they are not a "sentence" of their own
+            let is_numbered = self.from.is_some_and(|(from, _)| from + 1 < pos) && prev.chars().all(char::is_numeric);
+            let abbreviation = || last_word(prev).trim();
+            // only a dot ends an abbreviation, while "etc!" or "Dr?" always end sentences
+            let is_dot = marker.starts_with('.') && !marker.contains(|ch| ch != '.' && cfg.is_terminal(ch));
+
+            let merge = if is_hard_break(marker, next.unwrap_or_default(), cfg) {
+                None
+            } else if ends_with_whitespace(prev) && !is_terminal_run(prev, cfg) {
+                Some((MergeKind::DetachedTerminal, marker.trim()))
+            } else if is_item(prev)?
+                || is_dot
+                    && !is_numbered
+                    && (cfg.abbreviations.is_match(prev)
+                        || cfg.extra_abbreviations.is_match(prev)
+                        || matches_or_false(abbreviations_for(cfg.language), prev)?)
+                    && !matches_or_false(&STREET, prev)?
+            {
+                Some((MergeKind::Abbreviation, abbreviation()))
+            } else {
+                match next {
+                    Some(next) if !is_item(next)? => {
+                        if case_rules && cfg.require_capital_start && matches_or_false(&LONE_WORD, next)? {
+                            Some((MergeKind::LowercaseFollow, next))
+                        } else if is_dot
+                            && (matches_or_false(&ENDS_IN_DATE_DIGITS, prev)? && matches_or_false(&MONTH, next)?
+                                || next.starts_with(char::is_numeric) && matches_or_false(&BEFORE_NUMBER, prev)?
+                                || matches_or_false(&MIDDLE_INITIAL_END, prev)?
+                                    && matches_or_false(&UPPER_WORD_START, next)?)
+                        {
+                            Some((MergeKind::Abbreviation, abbreviation()))
+                        } else {
+                            None
+                        }
+                    }
+                    _ => None,
+                }
+            };
+
+            // the typewriter convention tells a sentence end from an abbreviation
+            let merge = merge.filter(|&(kind, _)| {
+                kind != MergeKind::Abbreviation || !cfg.double_space_hint || !is_double_spaced(marker)
+            });
+
+            if let Some((kind, merged)) = merge {
+                if next.is_some() {
+                    on_merge(kind, merged, span.end);
+                }
+            } else if let Some((_, start)) = self.from.take() {
+                return Ok(Some(start..span.end));
+            }
+        }
+
+        Ok(self.from.take().map(|(_, start)| start..text.len()))
+    }
+
+    /// Take the next span of the text, a match of the segmenter or the text between two of them.
+    fn next_span(&mut self, text: &str) -> Result<Option<Range<usize>>, SegmentError> {
+        if let Some(span) = self.lookahead.take().or_else(|| self.next_match.take()) {
+            return Ok(Some(span));
+        }
+
+        let start = self.match_end;
+        match self.segmenter.find_from_pos(text, start).map_err(|err| SegmentError::regex(text, err))? {
+            None if start >= text.len() => Ok(None),
             None => {
-                _last = Some(current);
+                self.match_end = text.len();
+                Ok(Some(start..text.len()))
             }
-            Some(ref mut last) => {
-                if (cfg.join_on_lowercase || BEFORE_LOWER.is_match(last).unwrap())
-                    && LOWER_WORD.is_match(&current).unwrap()
-                    || (shorter_than_a_typical_sentence(current.len(), last.len())
-                        && (is_open(last, ('(', ')'))
-                            && (is_not_open(&current, ('(', ')'))
-                                || last.ends_with(" et al. ")
-                                || (UPPER_CASE_END.is_match(last).unwrap()
-                                    && UPPER_CASE_START.is_match(&current).unwrap())))
-                        || (is_open(last, ('[', ']'))
-                            && (is_not_open(&current, ('[', ']'))
-                                || last.ends_with(" et al. ")
-                                || (UPPER_CASE_END.is_match(last).unwrap()
-                                    && UPPER_CASE_START.is_match(&current).unwrap()))))
-                    || CONTINUATIONS.is_match(&current).unwrap()
-                {
-                    last.push_str(&current)
+            Some(found) => {
+                self.match_end = found.end();
+                if found.start() > start {
+                    self.next_match = Some(found.range());
+                    Ok(Some(start..found.start()))
                 } else {
-                    res.push(last.trim().to_string());
-                    _last = Some(current);
+                    Ok(Some(found.range()))
                 }
             }
         }
     }
 
-    _last.inspect(|last| res.push(last.trim().to_string()));
-    res
+    /// Look at the span after the current one, without taking it.
+    fn peek_span(&mut self, text: &str) -> Result<Option<Range<usize>>, SegmentError> {
+        if self.lookahead.is_none() {
+            self.lookahead = self.next_span(text)?;
+        }
+        Ok(self.lookahead.clone())
+    }
 }
 
-/// Join spans that match the `ABBREVIATIONS` pattern.
-fn join_abbreviations(spans: &[&str]) -> Vec<String> {
-    let mut res = Vec::with_capacity(spans.len());
-    let mut put = |start, end| res.push(spans[start..end].join(""));
+/// The range of the text without its leading and trailing whitespace.
+fn trim_range(text: &str, range: Range<usize>) -> Range<usize> {
+    let span = &text[range.clone()];
+    let start = range.start + span.len() - span.trim_start().len();
+    start..start + span.trim().len()
+}
 
-    fn ends_with_whitespace(str: &str) -> bool {
-        str.bytes().next_back().is_some_and(|ch| ch.is_ascii_whitespace())
+/// Check if the `current` span continues the sentence `last`, and why, with the text that decided it.
+///
+/// The `nesting` of `last` lists the brackets, then the quotes of the [SegmentConfig::balance_quotes].
+/// The `is_start` closure, if any, decides in place of the case rules.
+fn merge_kind<'a>(
+    last: &'a str,
+    current: &'a str,
+    nesting: &[Nesting],
+    cfg: &'a SegmentConfig,
+    is_start: Option<&dyn Fn(&str) -> bool>,
+    shorter_than_a_typical_sentence: &mut dyn FnMut(usize, usize) -> bool,
+) -> Result<Option<(MergeKind, Cow<'a, str>)>, SegmentError> {
+    let separator = &last[last.trim_end().len()..];
+    if is_hard_break(separator, current, cfg) {
+        return Ok(None);
     }
 
-    let mut from = None;
-    for pos in 0..spans.len() {
-        if pos % 2 == 0 {
-            from = from.or(Some(pos));
-        } else {
-            let prev = spans[pos - 1];
-            let marker = spans[pos];
-            let next = spans.get(pos + 1);
+    // a run of terminals, like the second "?!" of "Wow?! ?! Next", is not a sentence of its own
+    if is_terminal_run(current, cfg) && !separator.contains("\n\n") {
+        return Ok(Some((MergeKind::DetachedTerminal, current.trim().into())));
+    }
 
-            if ends_with_whitespace(prev)
-                || marker.starts_with('.') && (ABBREVIATIONS.is_match(prev).unwrap())
-                || next.is_some_and(|&next| {
-                    LONE_WORD.is_match(next).unwrap()
-                        || (ENDS_IN_DATE_DIGITS.is_match(prev).unwrap() && MONTH.is_match(next).unwrap())
-                        || (MIDDLE_INITIAL_END.is_match(prev).unwrap() && UPPER_WORD_START.is_match(next).unwrap())
-                })
-            {
-                continue;
-            } else {
-                from.inspect(|&from| put(from, pos + 1));
-                from = None;
+    // the suffix patterns only need the last word of `last` and its trailing spaces
+    let tail = last_word(last);
+    let [parens, squares, quotes @ ..] = nesting else { unreachable!("brackets are always tracked") };
+
+    if let Some(is_start) = is_start {
+        // a paragraph break always splits
+        if !separator.contains("\n\n") && !is_start(current.trim()) {
+            let word = current.split_whitespace().next().unwrap_or_default();
+            return Ok(Some((MergeKind::Continuation, word.into())));
+        }
+    } else if cfg.require_capital_start {
+        let lower_word = if cfg.allow_camelcase_start { &LOWER_WORD } else { &LOWER_START_WORD };
+        if let Some(word) = find_or_none(lower_word, current)? {
+            if cfg.join_on_lowercase || matches_or_false(&BEFORE_LOWER, tail)? {
+                return Ok(Some((MergeKind::LowercaseFollow, word.as_str().into())));
             }
         }
+        if let Some(word) = find_or_none(&CONTINUATIONS, current)? {
+            return Ok(Some((MergeKind::Continuation, word.as_str().into())));
+        }
+    }
+    if let Some(token) = no_start_token(current, cfg) {
+        return Ok(Some((MergeKind::Continuation, token.into())));
+    }
+    if !separator.contains("\n\n") {
+        if let Some(quote) = quotes.iter().find(|n| n.is_open()) {
+            return Ok(Some((MergeKind::OpenQuote, quote.brackets.0.to_string().into())));
+        }
     }
 
-    from.inspect(|&from| put(from, spans.len()));
-    res
+    // checked last, so only the joins that depend on the length are reported
+    let is_joined_in = |bracket: &Nesting| -> Result<bool, SegmentError> {
+        Ok(bracket.is_open()
+            && (is_not_open(current, bracket.brackets)
+                || last.ends_with(" et al. ")
+                || (matches_or_false(&UPPER_CASE_END, tail)? && matches_or_false(&UPPER_CASE_START, current)?)))
+    };
+    if (is_joined_in(parens)? || is_joined_in(squares)?) && shorter_than_a_typical_sentence(current.len(), last.len()) {
+        let fragment = if current.len() < last.len() { current } else { last };
+        return Ok(Some((MergeKind::BracketShortFragment, fragment.trim().into())));
+    }
+
+    Ok(None)
 }
 
-/// Check if the span ends with an unclosed ASCII `bracket`.
-fn is_open(span: &str, brackets: (char, char)) -> bool {
-    let mut offset = span.find(brackets.0);
-    let mut nesting = if offset.is_none() { 0 } else { 1 };
+/// The [Regex::is_match] of the span, or `false` if the pattern exceeds the backtrack limit on it,
+/// like on a very long span without spaces, so the heuristic does not apply and the span is split.
+/// Other errors of the pattern are wrapped into a [SegmentError::Regex].
+fn matches_or_false(regex: &Regex, span: &str) -> Result<bool, SegmentError> {
+    match regex.is_match(span) {
+        Err(err) if is_backtrack_limit(&err) => {
+            warn_backtrack_limit(span);
+            Ok(false)
+        }
+        res => res.map_err(|err| SegmentError::regex(span, err)),
+    }
+}
 
-    while let Some(idx) = offset {
-        let idx = idx + 1;
-        let opener = span[idx..].find(brackets.0).map(|i| i + idx);
-        let closer = span[idx..].find(brackets.1).map(|i| i + idx);
+/// The [Regex::find] in the span, or `None` if the pattern exceeds the backtrack limit on it,
+/// like [matches_or_false].
+fn find_or_none<'t>(regex: &Regex, span: &'t str) -> Result<Option<fancy_regex::Match<'t>>, SegmentError> {
+    match regex.find(span) {
+        Err(err) if is_backtrack_limit(&err) => {
+            warn_backtrack_limit(span);
+            Ok(None)
+        }
+        res => res.map_err(|err| SegmentError::regex(span, err)),
+    }
+}
 
-        match (opener, closer) {
-            (None, None) => {
-                offset = None;
-            }
-            (None, Some(_)) => {
-                offset = closer;
-                nesting -= 1;
-            }
-            (Some(_), None) => {
-                offset = opener;
-                nesting += 1;
-            }
-            (Some(op), Some(cl)) => match op.cmp(&cl) {
-                Ordering::Less => {
-                    offset = opener;
-                    nesting += 1;
-                }
-                Ordering::Greater => {
-                    offset = closer;
-                    nesting -= 1;
-                }
-                Ordering::Equal => {
-                    unreachable!("open and closer have the same position")
-                }
+fn is_backtrack_limit(err: &fancy_regex::Error) -> bool {
+    matches!(err, fancy_regex::Error::RuntimeError(fancy_regex::RuntimeError::BacktrackLimitExceeded))
+}
+
+/// Report the first span a pattern exceeded the backtrack limit on, if the `backtrack-warnings` feature is enabled.
+#[cfg(feature = "backtrack-warnings")]
+fn warn_backtrack_limit(span: &str) {
+    static WARNED: std::sync::Once = std::sync::Once::new();
+    WARNED.call_once(|| {
+        eprintln!("segtok: a pattern exceeded the backtrack limit on a span of {} bytes, taken as no match", span.len())
+    });
+}
+
+#[cfg(not(feature = "backtrack-warnings"))]
+fn warn_backtrack_limit(_span: &str) {}
+
+/// The end of the span from the whitespace before its last word,
+/// which is all the suffix patterns like [BEFORE_LOWER] have to see, including the word boundary.
+fn last_word(span: &str) -> &str {
+    let word = span.trim_end();
+    &span[word.rfind(char::is_whitespace).unwrap_or(0)..]
+}
+
+/// Check if the separator always splits sentences, no matter the text before it.
+fn is_hard_break(separator: &str, next: &str, cfg: &SegmentConfig) -> bool {
+    cfg.tab_is_boundary && separator.contains('\t')
+        || cfg.form_feed_is_break && separator.contains('\x0C')
+        || cfg.treat_bullets_as_boundaries && separator.contains('\n') && starts_with_bullet(next)
+        || cfg.pilcrow_is_break && starts_with_pilcrow(next)
+}
+
+/// Check if the separator ends with two or more spaces on the same line, like ".  " but not ".\n ".
+fn is_double_spaced(separator: &str) -> bool {
+    let spaces = &separator[separator.trim_end().len()..];
+    spaces.chars().count() >= 2 && !spaces.contains(['\n', '\r'])
+}
+
+/// Check if the span starts with a pilcrow before a capitalized word, like "¶ New paragraph".
+fn starts_with_pilcrow(span: &str) -> bool {
+    span.strip_prefix('¶').is_some_and(|rest| rest.trim_start().starts_with(char::is_uppercase))
+}
+
+/// Check if the span starts with a bullet glyph and a space, like "• item" or "- item".
+fn starts_with_bullet(span: &str) -> bool {
+    span.strip_prefix(['•', '‣', '·', '*', '-'])
+        .is_some_and(|rest| rest.starts_with(|ch: char| ch.is_whitespace() && ch != '\n'))
+}
+
+/// Check if the span only has terminals, the closing brackets and quotes after them, and spaces.
+fn is_terminal_run(span: &str, cfg: &SegmentConfig) -> bool {
+    span.chars().all(|ch| ch.is_whitespace() || cfg.is_terminal(ch) || cfg.is_closing(ch))
+}
+
+/// Find the [SegmentConfig::no_start_tokens] the span starts with.
+fn no_start_token<'a>(span: &str, cfg: &'a SegmentConfig) -> Option<&'a str> {
+    cfg.no_start_tokens
+        .iter()
+        .map(String::as_str)
+        .find(|&token| span.strip_prefix(token).is_some_and(|rest| !rest.starts_with(char::is_alphanumeric)))
+}
+
+/// The nesting of a bracket pair in a span from its first opener on,
+/// which tells if the span ends with an unclosed bracket, like "(see Fig. 1".
+///
+/// Parts are [pushed](Self::push) as the span grows, so the span is scanned only once.
+struct Nesting {
+    brackets: (char, char),
+    depth: Option<isize>,
+}
+
+impl Nesting {
+    fn new(brackets: (char, char)) -> Self {
+        Self { brackets, depth: None }
+    }
+
+    /// Continue the span with the `part`.
+    fn push(&mut self, part: &str) {
+        let (opener, closer) = self.brackets;
+        let (depth, part) = match self.depth {
+            Some(depth) => (depth, part),
+            None => match part.find(opener) {
+                Some(idx) => (0, &part[idx..]),
+                None => return,
             },
-        }
+        };
+
+        let depth = part.chars().fold(depth, |depth, ch| depth + isize::from(ch == opener) - isize::from(ch == closer));
+        self.depth = Some(depth);
     }
 
-    nesting > 0
+    /// Start an empty span.
+    fn clear(&mut self) {
+        self.depth = None;
+    }
+
+    fn is_open(&self) -> bool {
+        self.depth.is_some_and(|depth| depth > 0)
+    }
 }
 
-/// Check if the span starts with an unopened ASCII `bracket`.
+/// Check if the span starts with an unopened `bracket`.
 fn is_not_open(span: &str, brackets: (char, char)) -> bool {
     let mut offset = span.rfind(brackets.1);
     let mut nesting = if offset.is_none() { 0 } else { 1 };
@@ -477,6 +1795,13 @@ mod tests {
         ])
     }
 
+    #[test]
+    fn try_long_joined_line() {
+        // every span joins the previous one, which must not rescan the whole sentence
+        let text = "ab cd. ".repeat(10_000);
+        assert_eq!(split_multi(&text, Default::default()), [text.trim()]);
+    }
+
     #[test]
     fn try_multiline() {
         let text = "This is a\nmultiline sentence. And this is Mr.\nAbbrevation.";
@@ -485,6 +1810,15 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn try_multiline_as_spaces() {
+        let text = "This is a\nmultiline sentence. And this is Mr.\n   Abbrevation.";
+        let keep = split_multi(text, SegmentConfig::default().internal_newline(InternalNewline::Keep));
+        assert_eq!(keep, ["This is a\nmultiline sentence.", "And this is Mr.\n   Abbrevation."]);
+        let space = split_multi(text, SegmentConfig::default().internal_newline(InternalNewline::Space));
+        assert_eq!(space, ["This is a multiline sentence.", "And this is Mr. Abbrevation."]);
+    }
+
     #[test]
     fn try_linebreak() {
         let text = "This is a\nmultiline sentence.";
@@ -493,6 +1827,653 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn try_allow_camelcase_start() {
+        let text = "It binds the protein (see Fig. 1.) mRNA levels rose in the U.S.A. iPhone sales did, too.";
+        assert_eq!(split_multi(text, Default::default()).len(), 3);
+
+        let cfg = SegmentConfig::default().allow_camelcase_start(false);
+        assert_eq!(split_multi(text, cfg.clone()), [text]);
+        assert_eq!(split_multi("Found in E. coli. mRNA was there.", cfg).len(), 2);
+    }
+
+    #[test]
+    fn try_ellipsis_in_quotes() {
+        // a quoted ellipsis before a lower-case word is part of the sentence, like a quoted terminal
+        for cfg in [SegmentConfig::default(), SegmentConfig::default().split_on_ellipsis(true)] {
+            let text = "'Well...' she said. Then he left.";
+            assert_eq!(split_multi(text, cfg.clone()), ["'Well...' she said.", "Then he left."]);
+            assert_eq!(split_multi("\u{201C}Well\u{2026}\u{201D} she said.", cfg).len(), 1);
+        }
+
+        // inside a balanced quote, an ellipsis before a capital does not end the sentence
+        let text = "He said \u{201C}wait\u{2026} I know\u{201D} and left.";
+        let cfg = SegmentConfig::default().split_on_ellipsis(true);
+        assert_eq!(split_multi(text, cfg.clone()).len(), 2);
+        assert_eq!(split_multi(text, cfg.balance_quotes(Some(Lang::English))), [text]);
+    }
+
+    #[test]
+    fn try_ellipsis_then_quote_then_capital() {
+        // the dots of "..." always end a sentence before a capital, with or without closing quotes
+        for cfg in [SegmentConfig::default(), SegmentConfig::default().split_on_ellipsis(true)] {
+            assert_eq!(split_multi("He left...' Then silence.", cfg), ["He left...'", "Then silence."]);
+        }
+
+        // while "…" only does if it is a terminal, and is part of the sentence by default
+        let examples = [
+            ("He left\u{2026} Then silence.", "He left\u{2026}"),
+            ("\u{201C}He left\u{2026}\u{201D} Then silence.", "\u{201C}He left\u{2026}\u{201D}"),
+        ];
+        for (text, first) in examples {
+            assert_eq!(split_multi(text, SegmentConfig::default().split_on_ellipsis(true)), [first, "Then silence."]);
+            assert_eq!(split_multi(text, SegmentConfig::default()), [text]);
+            assert_eq!(split_single(text, SegmentConfig::default()), [text]);
+        }
+
+        // an unquoted ellipsis before a lower-case word still ends the sentence, see `try_continuations`
+        assert_eq!(split_multi("Wait... what?", SegmentConfig::default()), ["Wait...", "what?"]);
+    }
+
+    #[test]
+    fn try_lowercase_transcript() {
+        let text = "so i went to the store. and then i saw him. okay. is that right? yes it is.";
+        let cfg = SegmentConfig::default().require_capital_start(false);
+        let expected = ["so i went to the store.", "and then i saw him.", "okay.", "is that right?", "yes it is."];
+        assert_eq!(split_single(text, cfg.clone()), expected);
+        assert_eq!(split_multi(text, cfg), expected);
+    }
+
+    #[test]
+    fn try_lowercase_transcript_default() {
+        let text = "so i went to the store. and then i saw him. okay. is that right?";
+        let expected = [text];
+        assert_eq!(split_single(text, Default::default()), expected);
+    }
+
+    #[test]
+    fn try_lowercase_transcript_abbreviation() {
+        let text = "it costs approx. ten bucks. he said so.";
+        let cfg = SegmentConfig::default().require_capital_start(false);
+        let expected = ["it costs approx. ten bucks.", "he said so."];
+        assert_eq!(split_single(text, cfg), expected);
+    }
+
+    #[test]
+    fn try_missing_space() {
+        let text = "It was the end.Next one starts here!And this one.";
+        let cfg = SegmentConfig::default().split_on_missing_space(true);
+        let expected = ["It was the end.", "Next one starts here!", "And this one."];
+        assert_eq!(split_single(text, cfg), expected);
+        assert_eq!(split_single(text, Default::default()), [text]);
+    }
+
+    #[test]
+    fn try_missing_space_exceptions() {
+        let cfg = SegmentConfig::default().split_on_missing_space(true);
+        for text in ["Pi is about 3.14 in size.", "He lives in the U.S.A now.", "See e.g.Smith for that."] {
+            assert_eq!(split_single(text, cfg.clone()), [text]);
+        }
+    }
+
+    #[test]
+    fn try_pattern() {
+        assert_eq!(segmenter_pattern(1, Default::default()), DO_NOT_CROSS_LINES.as_str());
+        assert!(segmenter_pattern(2, Default::default()).contains(r"\n{2,}"));
+
+        let cfg = SegmentConfig::default().split_on_missing_space(true);
+        assert!(segmenter_pattern(1, cfg.clone()).contains("capitalized word"));
+        assert!(Regex::new(&segmenter_pattern(1, cfg)).is_ok());
+    }
+
+    #[test]
+    fn try_no_start_tokens() {
+        let text = "The pressure was 120 mmHg. mmHg. Is an unusual start. The end.";
+        let cfg = SegmentConfig::default().no_start_tokens(["mmHg", "Is"]);
+        let expected = ["The pressure was 120 mmHg. mmHg. Is an unusual start.", "The end."];
+        assert_eq!(split_single(text, cfg), expected);
+
+        let cfg = SegmentConfig::default().no_start_tokens(["I"]);
+        assert_eq!(split_single("This is it. Is it?", cfg), ["This is it.", "Is it?"]);
+    }
+
+    #[test]
+    fn try_outline_items() {
+        let cfg = SegmentConfig::default().outline_items(true);
+        for sentences in [
+            ["A. First point.", "B. Second point.", "C. Third point."],
+            ["i. First point.", "ii. Second point.", "iii. Third point."],
+            ["Intro text.", "a. first point.", "b. second point."],
+            ["See point A.", "B. Second point.", "2. Third point."],
+        ] {
+            assert_eq!(split_single(&sentences.join(" "), cfg.clone()), sentences);
+        }
+    }
+
+    #[test]
+    fn try_outline_items_keep_initials() {
+        let cfg = SegmentConfig::default().outline_items(true);
+        let sentences = ["Written by A. McArthur, K. Elvin, and D. Eden.", "This is Mr. A. Starr over there."];
+        assert_eq!(split_single(&sentences.join(" "), cfg), sentences);
+    }
+
+    #[test]
+    fn try_exclude_terminals() {
+        let text = "A comment\u{3002} Still the same! Next one.";
+        let expected = ["A comment\u{3002}", "Still the same!", "Next one."];
+        assert_eq!(split_single(text, Default::default()), expected);
+
+        let cfg = SegmentConfig::default().exclude_terminals(['\u{3002}']);
+        let expected = ["A comment\u{3002} Still the same!", "Next one."];
+        assert_eq!(split_single(text, cfg), expected);
+
+        let cfg = SegmentConfig::default().exclude_terminals(SENTENCE_TERMINAL_CHARS);
+        assert_eq!(split_single(text, cfg), [text]);
+    }
+
+    #[test]
+    fn try_guillemets() {
+        test_split_single(["«Il est parti.»", "Ensuite nous sommes partis.", "Il a dit «Allez.\"»", "Et voilà."])
+    }
+
+    #[test]
+    fn try_cjk_corner_brackets() {
+        test_split_single(["He wrote 「Let's go.」", "Then they left 『together!』", "And so on."])
+    }
+
+    #[test]
+    fn try_doubled_quotes() {
+        test_split_single(["He said \"she said 'go.'\"", "They went ’’home.’’", "The end."])
+    }
+
+    #[test]
+    fn try_custom_closing_quotes() {
+        let text = "He said «Go.» Then left.";
+        let cfg = SegmentConfig::default().closing_quotes(['"']);
+        assert_eq!(split_single(text, cfg), [text]);
+    }
+
+    #[test]
+    fn try_tab_is_boundary() {
+        let cfg = SegmentConfig::default().tab_is_boundary(true);
+        assert_eq!(split_multi("field1\tfield2\tfield3", cfg.clone()), ["field1", "field2", "field3"]);
+        assert_eq!(split_multi("Dr. \tand more. \t(brackets", cfg), ["Dr.", "and more.", "(brackets"]);
+        assert_eq!(split_multi("field1\tfield2", Default::default()), ["field1\tfield2"]);
+    }
+
+    #[test]
+    fn try_form_feed_is_break() {
+        assert_eq!(split_multi("Section 1.\x0CSection 2.", Default::default()), ["Section 1.", "Section 2."]);
+        assert_eq!(split_multi("See Sec. \x0C\nsection two", Default::default()), ["See Sec.", "section two"]);
+        assert_eq!(split_multi("Page one\x0CPage two", Default::default()), ["Page one", "Page two"]);
+        let cfg = SegmentConfig::default().form_feed_is_break(false);
+        assert_eq!(split_multi("Page one\x0CPage two", cfg), ["Page one\x0CPage two"]);
+    }
+
+    #[test]
+    fn try_bullets_as_boundaries() {
+        let cfg = SegmentConfig::default().treat_bullets_as_boundaries(true);
+        let text = "• First item\n• Second item\n  ‣ nested item\n· third\n- fourth\n* fifth";
+        let expected = ["• First item", "• Second item", "‣ nested item", "· third", "- fourth", "* fifth"];
+        assert_eq!(split_multi(text, cfg.clone()), expected);
+        assert_eq!(split_multi(text, Default::default()), [text]);
+
+        let text = "List, e.g.\n- one item, i.e.\n- another. Done.";
+        assert_eq!(split_multi(text, cfg.clone()), ["List, e.g.", "- one item, i.e.", "- another.", "Done."]);
+
+        let text = "Costs are\n-5 dollars or a *bold\n*move.";
+        assert_eq!(split_multi(text, cfg), [text]);
+    }
+
+    #[test]
+    fn try_ethiopic_script() {
+        let text = "ሰላም ነው። እንዴት ነህ፧ ደህና ነኝ።";
+        let cfg = SegmentConfig::default().script(Some(Script::Ethiopic));
+        assert_eq!(split_multi(text, cfg), ["ሰላም ነው።", "እንዴት ነህ፧", "ደህና ነኝ።"]);
+        assert_eq!(split_multi(text, Default::default()), [text]);
+    }
+
+    #[test]
+    fn try_tibetan_script() {
+        let text = "བཀྲ་ཤིས་བདེ་ལེགས། ཁྱེད་རང་སྐུ་གཟུགས་བདེ་པོ་ཡིན་པས།ང་བདེ་པོ་ཡིན༎";
+        let cfg = SegmentConfig::default().script(Some(Script::Tibetan));
+        let expected = ["བཀྲ་ཤིས་བདེ་ལེགས།", "ཁྱེད་རང་སྐུ་གཟུགས་བདེ་པོ་ཡིན་པས།", "ང་བདེ་པོ་ཡིན༎"];
+        assert_eq!(split_multi(text, cfg.clone()), expected);
+        assert_eq!(split_single(text, cfg.clone()), expected);
+        assert_eq!(split_multi(text, Default::default()), [text]);
+
+        // a run of shads, as in "། །" between verses, ends a single sentence
+        assert_eq!(split_multi("ཀ་ཁ།། ག་ང། །ཅ་ཆ།", cfg), ["ཀ་ཁ།།", "ག་ང། །", "ཅ་ཆ།"]);
+    }
+
+    #[test]
+    fn try_mongolian_script() {
+        let text = "ᠮᠣᠩᠭᠣᠯ ᠬᠡᠯᠡ᠃ ᠰᠠᠶᠢᠨ ᠪᠠᠶᠢᠨ᠎ᠠ᠃";
+        let cfg = SegmentConfig::default().script(Some(Script::Mongolian));
+        assert_eq!(split_multi(text, cfg), ["ᠮᠣᠩᠭᠣᠯ ᠬᠡᠯᠡ᠃", "ᠰᠠᠶᠢᠨ ᠪᠠᠶᠢᠨ᠎ᠠ᠃"]);
+        assert_eq!(split_multi(text, Default::default()), [text]);
+    }
+
+    #[test]
+    fn try_abbreviation_list() {
+        let text = "See Ref. 3 for details. And the Sec. Five here.";
+        assert_eq!(split_multi(text, Default::default()), ["See Ref.", "3 for details.", "And the Sec.", "Five here."]);
+
+        let cfg = SegmentConfig::default().abbreviations(AbbreviationList::new(["Ref", "Sec"]));
+        assert_eq!(split_multi(text, cfg), ["See Ref. 3 for details.", "And the Sec. Five here."]);
+
+        let cfg = SegmentConfig::default().abbreviations(AbbreviationList::case_insensitive(["ref"]));
+        assert_eq!(split_multi("See REF. 3 for details.", cfg), ["See REF. 3 for details."]);
+
+        let cfg = SegmentConfig::default().extra_abbreviations(vec!["Ref".to_string(), "Sec.".to_string()]);
+        assert_eq!(split_multi(text, cfg.clone()), ["See Ref. 3 for details.", "And the Sec. Five here."]);
+        assert_eq!(split_single(text, cfg), ["See Ref. 3 for details.", "And the Sec. Five here."]);
+
+        let cfg = SegmentConfig::default()
+            .abbreviations(AbbreviationList::case_insensitive(["ref"]))
+            .extra_abbreviations(["Fig"])
+            .extra_abbreviations(["Sec"]);
+        let text = "See REF. 3 and Fig. 4 here. The FIG. Five. And Sec. Six.";
+        assert_eq!(split_multi(text, cfg), ["See REF. 3 and Fig. 4 here.", "The FIG.", "Five.", "And Sec. Six."]);
+    }
+
+    #[test]
+    fn try_citations() {
+        test_split_single(["This is shown in [1].", "Next claim [2,3].", "Third one [12, 13]."]);
+        test_split_single(["The [2] result [3-5].", "Also (Smith et al. [4]).", "We follow [Ref. 2].", "End."]);
+    }
+
+    #[test]
+    fn try_emoji_terminal() {
+        let cfg = SegmentConfig::default().emoji_terminal(true);
+        let text = "See you soon 👋 Take care 🙂 Bye! 👋🏽 Later 👨‍👩‍👧 ❤️ Ok";
+        let expected = ["See you soon 👋", "Take care 🙂", "Bye!", "👋🏽 Later 👨‍👩‍👧 ❤️", "Ok"];
+        assert_eq!(split_multi(text, cfg.clone()), expected);
+        assert_eq!(split_multi("I'm 🙂 and fine 👍 ok", cfg), ["I'm 🙂 and fine 👍 ok"]);
+        assert_eq!(split_multi(text, Default::default()), ["See you soon 👋 Take care 🙂 Bye!", "👋🏽 Later 👨‍👩‍👧 ❤️ Ok"]);
+    }
+
+    #[test]
+    fn try_min_sentence_tokens() {
+        let text = "He left. Quickly. Then he came back.\n Ok!\n\nYes. Surely not.";
+        let cfg = SegmentConfig::default().min_sentence_tokens(3);
+        assert_eq!(
+            split_multi(text, cfg.clone()),
+            ["He left. Quickly.", "Then he came back.\n Ok!", "Yes.", "Surely not."]
+        );
+
+        let cfg = cfg.internal_newline(InternalNewline::Space);
+        assert_eq!(split_multi(text, cfg)[1], "Then he came back. Ok!");
+
+        let spans = split_multi_spans(text, SegmentConfig::default().min_sentence_tokens(3));
+        assert_eq!(&text[spans[1].clone()], "Then he came back.\n Ok!");
+        assert_eq!(split_multi(text, Default::default()).len(), 6);
+    }
+
+    #[test]
+    fn try_pilcrow_is_break() {
+        let cfg = SegmentConfig::default().pilcrow_is_break(true);
+        let text = "§ 5 applies ¶ New paragraph, see ¶ 12 and ¶¶ 3-4. Dr. ¶ Old one. ¶Next";
+        let expected = ["§ 5 applies", "¶ New paragraph, see ¶ 12 and ¶¶ 3-4.", "Dr.", "¶ Old one.", "¶Next"];
+        assert_eq!(split_multi(text, cfg), expected);
+        let expected = ["§ 5 applies ¶ New paragraph, see ¶ 12 and ¶¶ 3-4.", "Dr. ¶ Old one.", "¶Next"];
+        assert_eq!(split_multi(text, Default::default()), expected);
+    }
+
+    #[test]
+    fn try_inline_math() {
+        let cfg = SegmentConfig::default().inline_math(true);
+        let text = "The value $x = 3.14$. Next, $a! = b?$ holds. So does \\(p. q\\). It costs $5. Then $$E. F$$ ends.";
+        let expected = [
+            "The value $x = 3.14$.",
+            "Next, $a! = b?$ holds.",
+            "So does \\(p. q\\).",
+            "It costs $5.",
+            "Then $$E. F$$ ends.",
+        ];
+        assert_eq!(split_multi(text, cfg.clone()), expected);
+        assert_eq!(split_single(text, cfg.clone()), expected);
+
+        let text = "Let $n! > 2$. Then $n. M$ is so.";
+        assert_eq!(split_multi(text, Default::default()), ["Let $n!", "> 2$.", "Then $n.", "M$ is so."]);
+        assert_eq!(split_multi(text, cfg.min_sentence_tokens(3)), ["Let $n! > 2$.", "Then $n. M$ is so."]);
+    }
+
+    #[test]
+    fn try_enumerated() {
+        let text = "The longest sentence comes first. Short one. A medium one here.";
+        let mut sentences = split_multi_enumerated(text, Default::default());
+        assert!(sentences.iter().map(|(_, s)| s).eq(&split_multi(text, Default::default())));
+
+        sentences.sort_by_key(|(_, sentence)| sentence.len());
+        assert_eq!(sentences.iter().map(|&(idx, _)| idx).collect::<Vec<_>>(), [1, 2, 0]);
+        sentences.sort();
+        assert_eq!(sentences.iter().map(|&(idx, _)| idx).collect::<Vec<_>>(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn try_abbreviation_at_end() {
+        let texts = [
+            "Apples, pears, and so on etc.",
+            "As shown by Smith et al.",
+            "Use a fruit, i.e.",
+            "Bring pens, paper etc. \n",
+        ];
+        for text in texts {
+            assert_eq!(split_multi(text, Default::default()), [text.trim()]);
+            assert_eq!(split_single(text, Default::default()), [text.trim()]);
+        }
+
+        let text = "First one. Then so on etc.";
+        assert_eq!(split_multi(text, Default::default()), ["First one.", "Then so on etc."]);
+    }
+
+    #[test]
+    fn try_split_multi_with() {
+        let text = "We shipped it. 42 bugs remain. Done.\n\n5 new ones.";
+        assert_eq!(
+            split_multi(text, Default::default()),
+            ["We shipped it.", "42 bugs remain.", "Done.", "5 new ones."]
+        );
+
+        let no_digit = |span: &str| !span.starts_with(char::is_numeric);
+        let expected = ["We shipped it. 42 bugs remain.", "Done.", "5 new ones."];
+        assert_eq!(split_multi_with(text, Default::default(), no_digit), expected);
+        assert_eq!(split_multi_with(text, Default::default(), |_| true), split_multi(text, Default::default()));
+
+        let cfg = SegmentConfig::default().require_capital_start(false);
+        let is_start = |span: &str| span.starts_with(|ch: char| ch.is_uppercase() || ch == 'ı' || ch == 'i');
+        let text = "Okul bitti. ılık bir gün. bu da değil.";
+        assert_eq!(split_multi_with(text, cfg, is_start), ["Okul bitti.", "ılık bir gün. bu da değil."]);
+
+        // the closure also splits where the case rules would join, but not after an abbreviation
+        let text = "He said so. and she left. Then e.g. more.";
+        assert_eq!(split_multi(text, Default::default()), ["He said so. and she left.", "Then e.g. more."]);
+        let expected = ["He said so.", "and she left.", "Then e.g. more."];
+        assert_eq!(split_multi_with(text, Default::default(), |_| true), expected);
+        assert_eq!(split_single_with(text, Default::default(), |_| true), expected);
+        assert_eq!(split_single_with(text, Default::default(), |_| false), [text]);
+    }
+
+    #[test]
+    fn try_backtrack_limit() {
+        let long = "x".repeat(200_000);
+        let text = format!("{long} A. B");
+        let expected = [format!("{long} A."), "B".to_string()];
+        assert_eq!(try_split_multi(&text, Default::default()), Ok(expected.to_vec()));
+        assert_eq!(split_single(&text, Default::default()), expected);
+
+        let text = format!("{}. B", "Aa".repeat(100_000));
+        assert_eq!(split_multi(&text, Default::default()).len(), 2);
+
+        let text = "One. Two.";
+        assert_eq!(try_split_multi(text, Default::default()), Ok(split_multi(text, Default::default())));
+        assert_eq!(try_split_single(text, Default::default()), Ok(split_single(text, Default::default())));
+    }
+
+    #[test]
+    fn try_double_space_hint() {
+        let cfg = SegmentConfig::default().double_space_hint(true);
+        assert_eq!(split_multi("End.  Next.", cfg.clone()), ["End.", "Next."]);
+        assert_eq!(
+            split_multi("He lives in the U.S.  Then he left.", cfg.clone()),
+            ["He lives in the U.S.", "Then he left."]
+        );
+        assert_eq!(split_multi("Written by J.  Doe today.", cfg.clone()), ["Written by J.", "Doe today."]);
+        assert_eq!(split_single("Ask Dr.  Smith now.", cfg.clone()), ["Ask Dr.", "Smith now."]);
+
+        for text in ["He lives in the U.S. Then he left.", "See e.g.  the table.", "The U.S.\n  Then he left."] {
+            assert_eq!(split_multi(text, cfg.clone()), [text]);
+        }
+        assert_eq!(split_multi("He lives in the U.S.  Then he left.", Default::default()).len(), 1);
+    }
+
+    #[test]
+    fn try_speaker_labels() {
+        let cfg = SegmentConfig::default().speaker_labels(true);
+        let text = "Alice: Hello. How are you?\nBob: Fine, I moved to the U.S. Dr. Smith: We meet at 10:30\nEve: ok";
+        let expected = [
+            "Alice: Hello.",
+            "How are you?",
+            "Bob: Fine, I moved to the U.S.",
+            "Dr. Smith: We meet at 10:30",
+            "Eve: ok",
+        ];
+        assert_eq!(split_multi(text, cfg.clone()), expected);
+        assert_eq!(split_single(text, cfg.clone()), expected);
+        assert_eq!(expected.map(speaker_label), [Some("Alice"), None, Some("Bob"), Some("Dr. Smith"), Some("Eve")]);
+
+        let spans = split_multi_spans(text, cfg.clone());
+        assert_eq!(spans.iter().map(|span| &text[span.clone()]).collect::<Vec<_>>(), expected);
+
+        assert_eq!(split_multi("The ratio was 3:1. Note the time 10:30 here.", cfg.clone()).len(), 2);
+        assert_eq!(split_multi(text, Default::default()).len(), 3);
+    }
+
+    #[test]
+    fn try_non_unix_linebreaks() {
+        for linebreak in ["\r\n", "\r", "\u{2028}"] {
+            let text = format!("First line{linebreak}of one.{linebreak}{linebreak}Second{linebreak}one.");
+            let expected = ["First line\nof one.", "Second\none."];
+            assert_eq!(split_multi(&text, Default::default()), expected);
+            assert_eq!(split_single(&text, Default::default()), ["First line", "of one.", "Second", "one."]);
+
+            let spans = split_multi_spans(&text, Default::default());
+            let sentences = spans.iter().map(|span| text[span.clone()].replace(linebreak, "\n")).collect::<Vec<_>>();
+            assert_eq!(sentences, expected);
+
+            // a paragraph break ends a sentence without a terminal, in the spans, too
+            let text = format!("Title{linebreak}{linebreak}Body{linebreak}text.");
+            assert_eq!(split_multi(&text, Default::default()), ["Title", "Body\ntext."]);
+            let spans = split_multi_spans(&text, Default::default());
+            assert_eq!(
+                spans.iter().map(|span| &text[span.clone()]).collect::<Vec<_>>(),
+                ["Title", &text[5 + 2 * linebreak.len()..]]
+            );
+            assert_eq!(split_single_spans(&text, Default::default()).len(), 3);
+        }
+    }
+
+    #[test]
+    fn try_language() {
+        let cfg = SegmentConfig::default().language(Lang::Portuguese);
+        let text = "A Dra. Costa leu a pág. 12 do livro. No séc. XX, V.Exa. Silva chegou. Fim.";
+        let expected = ["A Dra. Costa leu a pág. 12 do livro.", "No séc. XX, V.Exa. Silva chegou.", "Fim."];
+        assert_eq!(split_multi(text, cfg.clone()), expected);
+        assert_eq!(split_single(text, cfg), expected);
+        assert!(split_multi(text, Default::default()).len() > 3);
+    }
+
+    #[test]
+    fn try_genus_species() {
+        for text in [
+            "S. cerevisiae grows on glucose.",
+            "We saw E. coli and S. aureus in the samples.",
+            "The genus Staph. aureus was found.",
+            "Colonies of Strep. pyogenes were counted.",
+            "Bacillus sp. strain B1 and Salmonella enterica subsp. enterica were grown.",
+            "The Rosa var. alba and Brassica oleracea cv. capitata are plants.",
+        ] {
+            assert_eq!(split_multi(text, Default::default()), [text]);
+        }
+
+        let text = "We used E. coli. Next, M. musculus cells died. Bacillus spp. Done.";
+        let expected = ["We used E. coli.", "Next, M. musculus cells died.", "Bacillus spp.", "Done."];
+        assert_eq!(split_multi(text, Default::default()), expected);
+        assert_eq!(split_single(text, Default::default()), expected);
+    }
+
+    #[test]
+    fn try_abbreviation_before_other_terminal() {
+        for (text, expected) in [
+            ("And so on etc! Next.", &["And so on etc!", "Next."][..]),
+            ("And so on etc. next one.", &["And so on etc. next one."]),
+            ("And so on etc. Next one.", &["And so on etc.", "Next one."]),
+            ("See Fig? Next one.", &["See Fig?", "Next one."]),
+            ("See Fig. Next one.", &["See Fig. Next one."]),
+            ("Call John F! Kennedy now.", &["Call John F!", "Kennedy now."]),
+            ("On 3! März it was.", &["On 3!", "März it was."]),
+            ("See p?! 42 now.", &["See p?!", "42 now."]),
+        ] {
+            assert_eq!(split_multi(text, Default::default()), expected);
+            assert_eq!(split_single(text, Default::default()), expected);
+        }
+    }
+
+    #[test]
+    fn try_split_multi_iter() {
+        let text = "Ask Dr. Smith. He knows. and so on.\r\n\r\nBye.";
+        let mut sentences = split_multi_iter(text, Default::default());
+        assert_eq!(sentences.next().as_deref(), Some("Ask Dr. Smith."));
+        assert_eq!(sentences.collect::<Vec<_>>(), ["He knows. and so on.", "Bye."]);
+
+        let text = "One. Two\nlines.\n\n> Five.\n>\n> Six.\r\n\r\nSeven $$a. b$$ end.\n";
+        let cfg = SegmentConfig::default().strip_quote_prefix(true).inline_math(true);
+        let sentences = ["One.", "Two\nlines.", "Five.", "Six.", "Seven $$a. b$$ end."];
+        assert_eq!(split_multi_iter(text, cfg.clone()).collect::<Vec<_>>(), sentences);
+        assert_eq!(split_multi(text, cfg), sentences);
+
+        assert_eq!(split_multi_iter("", Default::default()).count(), 0);
+        assert_eq!(split_multi_iter("\n\n\n", Default::default()).count(), 0);
+    }
+
+    #[test]
+    fn try_page_numbers() {
+        let text = "See p. 42. Also pp. 10-12. Done.";
+        assert_eq!(split_multi(text, Default::default()), ["See p. 42.", "Also pp. 10-12.", "Done."]);
+        assert_eq!(split_single(text, Default::default()), ["See p. 42.", "Also pp. 10-12.", "Done."]);
+
+        let text = "Read para. 3 now. Then sec. 4 and Sec. 5 too.";
+        assert_eq!(split_multi(text, Default::default()), ["Read para. 3 now.", "Then sec. 4 and Sec. 5 too."]);
+
+        // without a number, the abbreviation may end the sentence
+        assert_eq!(split_multi("Read pp. Next one.", Default::default()), ["Read pp.", "Next one."]);
+    }
+
+    #[test]
+    fn try_case_citations() {
+        let texts = [
+            "Roe v. Wade established precedent.",
+            "It was A vs. B in court.",
+            "Brown v. Board of Education was decided in 1954.",
+        ];
+        for text in texts {
+            assert_eq!(split_multi(text, Default::default()), [text]);
+            assert_eq!(split_single(text, Default::default()), [text]);
+        }
+
+        let text = "See Smith v. Jones, 123 U.S. 456. Then he said v. Next one.";
+        let expected = ["See Smith v. Jones, 123 U.S. 456.", "Then he said v.", "Next one."];
+        assert_eq!(split_multi(text, Default::default()), expected);
+    }
+
+    #[test]
+    fn try_terminal_runs() {
+        assert_eq!(split_multi("Wow?? Really!!", Default::default()), ["Wow??", "Really!!"]);
+        assert_eq!(split_single("Wow?? Really!!", Default::default()), ["Wow??", "Really!!"]);
+
+        let text = "What...!? ... Next one. Hey. . . You. !! ?? Test ?! ?! Done.";
+        let expected = ["What...!? ...", "Next one.", "Hey. . .", "You. !! ??", "Test ?! ?!", "Done."];
+        assert_eq!(split_multi(text, Default::default()), expected);
+        assert_eq!(split_single(text, Default::default()), expected);
+    }
+
+    #[test]
+    fn try_no_empty_sentences() {
+        for text in ["", "  ", "\n\n", " \n \n\n \n"] {
+            assert_eq!(split_multi(text, Default::default()), Vec::<String>::new());
+            assert_eq!(split_single(text, Default::default()), Vec::<String>::new());
+        }
+        assert_eq!(split_single("One.\n\n \nTwo. ", Default::default()), ["One.", "Two."]);
+    }
+
+    #[test]
+    fn try_strip_quote_prefix() {
+        let cfg = SegmentConfig::default().strip_quote_prefix(true);
+        let text = "Sure, see below.\n\n> On Monday, Ann wrote:\n> The report is late. It\n> continues here.\n>\n> > Old reply. Really 3 > 2.";
+        let expected = [
+            "Sure, see below.",
+            "On Monday, Ann wrote:\nThe report is late.",
+            "It\ncontinues here.",
+            "Old reply.",
+            "Really 3 > 2.",
+        ];
+        assert_eq!(split_multi(text, cfg.clone()), expected);
+        assert_eq!(split_single("> One. Two\n>> three.", cfg), ["One.", "Two", "three."]);
+        assert_eq!(split_multi("> One.\n> two.", Default::default()), ["> One.", "> two."]);
+    }
+
+    #[test]
+    fn try_rule_line_is_break() {
+        let cfg = SegmentConfig::default().rule_line_is_break(true);
+        let text = "Section 1 ends here\n-------\nSection 2 starts\n  * * *  \r\nand ends here.\n===\n\n___\nLast one";
+        let expected = ["Section 1 ends here", "Section 2 starts", "and ends here.", "Last one"];
+        assert_eq!(split_multi(text, cfg.clone()), expected);
+
+        let text = "Not a -- rule.\n- item\n--\n-=-\nEnd.";
+        assert_eq!(split_multi(text, cfg.clone()), split_multi(text, Default::default()));
+        assert_eq!(
+            split_multi("Section 1.\n-------\nSection 2.", Default::default()),
+            ["Section 1.", "-------\nSection 2."]
+        );
+    }
+
+    #[test]
+    fn try_validate() {
+        assert_eq!(SegmentConfig::default().validate(), Ok(()));
+        let cfg = SegmentConfig::default().closing_quotes([]).exclude_terminals(SENTENCE_TERMINAL_CHARS);
+        assert_eq!(cfg.validate(), Ok(()));
+        let cfg = SegmentConfig::default().no_start_tokens(["mL", " mmHg"]);
+        assert_eq!(cfg.validate(), Err(ConfigError::NoStartToken { token: " mmHg".to_owned() }));
+        let cfg = SegmentConfig::default().no_start_tokens([""]);
+        assert_eq!(cfg.validate(), Err(ConfigError::NoStartToken { token: "".to_owned() }));
+    }
+
+    #[test]
+    fn try_saint_and_street() {
+        test_split_single(["St. Louis is a city.", "I live in St. Louis now."]);
+        test_split_single(["We met on Main St.", "Then we left."]);
+        test_split_single(["They live at 5 Elm St.", "We visit often."]);
+        assert_eq!(split_single("123 Main St. is the address.", Default::default()).len(), 1);
+        assert_eq!(split_single("Visit St. Paul's Cathedral.", Default::default()).len(), 1);
+    }
+
+    #[test]
+    fn try_balance_quotes() {
+        let cases = [
+            (Lang::English, ["He said: “Stop. Now!”", "Then he left."]),
+            (Lang::German, ["Er sagte: „Geh. Jetzt!“", "Dann ging er."]),
+            (Lang::French, ["Il dit : «Va. Maintenant!»", "Puis il partit."]),
+            (Lang::Japanese, ["「行け。 今だ！」", "彼は言った。"]),
+        ];
+
+        for (lang, expected) in cases {
+            let text = expected.join(" ");
+            assert_eq!(split_single(&text, SegmentConfig::default().balance_quotes(Some(lang))), expected);
+            assert_ne!(split_single(&text, Default::default()), expected, "{lang:?}");
+        }
+    }
+
+    #[test]
+    fn try_balance_quotes_until_paragraph() {
+        let cfg = SegmentConfig::default().balance_quotes(Some(Lang::German));
+        assert_eq!(split_multi("„Offen. Noch.\n\nNeu. Hier.", cfg), ["„Offen. Noch.", "Neu.", "Hier."]);
+    }
+
+    #[test]
+    fn try_abbreviation_before_bracket() {
+        test_split_single(["This is shown (see Fig. 3).", "Next sentence."]);
+        test_split_single(["It grows (Fig. 3a).", "Then it stops."]);
+        test_split_single(["Refer to Tab. 2 for data.", "And more (see Tab. 4).", "The end."]);
+    }
+
+    #[test]
+    fn try_numero() {
+        test_split_single(["See No. 1.", "Next one."]);
+        test_split_single(["Refer to rule No. 1.", "It was on Sept. 11.", "Then it ended."]);
+        test_split_single(["It is item No. 1 here."]);
+    }
+
     #[test]
     fn try_linebreak2() {
         let text = "Folding Beijing\nby Hao Jingfang";
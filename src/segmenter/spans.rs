@@ -0,0 +1,213 @@
+use std::ops::Range;
+
+use super::{split_multi_with_indent, split_single, InternalNewline, SegmentConfig, SegmentError};
+
+/// The same as [split_multi](super::split_multi), but returns the byte ranges of the sentences in the `text`.
+///
+/// The ranges exclude the whitespace around the sentences; a whitespace-only `text` has no sentences.
+pub fn split_multi_spans(text: &str, cfg: SegmentConfig) -> Vec<Range<usize>> {
+    let mut cursor = 0;
+
+    // the sentences must be verbatim slices of the text to locate them
+    split_multi_with_indent(text, cfg.internal_newline(InternalNewline::Keep))
+        .into_iter()
+        .map(|(sentence, prefix)| {
+            let start = cursor + prefix.len();
+            cursor = start + sentence.len();
+            start..cursor
+        })
+        .filter(|span| !span.is_empty())
+        .collect()
+}
+
+/// The same as [split_single](super::split_single), but returns the byte ranges of the sentences in the `text`.
+///
+/// As the lines are trimmed, the ranges exclude the whitespace around the sentences, too.
+///
+/// ```rust
+/// use segtok::segmenter::split_single_spans;
+///
+/// let text = "Ask Dr. Who.\n  Then leave.";
+/// let spans = split_single_spans(text, Default::default());
+/// assert_eq!(spans, [0..12, 15..26]);
+/// assert_eq!(&text[spans[0].clone()], "Ask Dr. Who.");
+/// ```
+pub fn split_single_spans(text: &str, cfg: SegmentConfig) -> Vec<Range<usize>> {
+    let mut cursor = 0;
+
+    // the sentences are verbatim slices of the text, with only whitespace between them
+    split_single(text, cfg.verbatim())
+        .into_iter()
+        .map(|sentence| {
+            let rest = &text[cursor..];
+            let start = cursor + rest.len() - rest.trim_start().len();
+            cursor = start + sentence.len();
+            start..cursor
+        })
+        .collect()
+}
+
+/// The same as [split_multi_spans], but only segments the window `text[range]`,
+/// while the returned ranges are relative to the whole `text`.
+///
+/// Fails if the `range` is out of bounds, reversed, or not on char boundaries.
+///
+/// ```rust
+/// use segtok::segmenter::split_multi_range;
+///
+/// let text = "Skipped. One. Two. Skipped.";
+/// assert_eq!(split_multi_range(text, 9..18, Default::default()), Ok(vec![9..13, 14..18]));
+/// ```
+pub fn split_multi_range(
+    text: &str,
+    range: Range<usize>,
+    cfg: SegmentConfig,
+) -> Result<Vec<Range<usize>>, SegmentError> {
+    let window = text.get(range.clone()).ok_or(SegmentError::Range { range: range.clone() })?;
+
+    let spans = split_multi_spans(window, cfg);
+    Ok(spans.into_iter().map(|span| span.start + range.start..span.end + range.start).collect())
+}
+
+/// Update the sentence ranges of a text after an edit, without segmenting the whole `text` again.
+///
+/// The `text` is the edited one, and the `old_spans` are the [split_multi_spans] of the text before the edit.
+/// The `edit` is the byte range of the inserted text in the edited `text`, which replaced `replaced` bytes
+/// of the old one. So typing "x" at 10 is `10..11` with `0` replaced, and deleting 3 bytes there is `10..10` with `3`.
+///
+/// Only the paragraphs around the edit are segmented: the ones touched by the edit,
+/// plus one paragraph before and after, as sentences are rarely joined across paragraph breaks.
+/// The ranges before this window are kept, and the ones after it are shifted by the change in length.
+/// If the edges of the window do not line up with the old ranges, the whole `text` is segmented instead,
+/// so the result always is the same as the [split_multi_spans] of the edited `text`.
+pub fn resegment(
+    text: &str,
+    old_spans: &[Range<usize>],
+    edit: Range<usize>,
+    replaced: usize,
+    cfg: SegmentConfig,
+) -> Vec<Range<usize>> {
+    let old_edit_end = edit.start + replaced;
+    let shift = |span: &Range<usize>| span.start - old_edit_end + edit.end..span.end - old_edit_end + edit.end;
+
+    let mut start = edit.start;
+    let mut end = edit.end;
+    for _ in 0..2 {
+        start = text[..start].trim_end().rfind("\n\n").unwrap_or(0);
+        let rest = text[end..].trim_start();
+        end = text.len() - rest.len() + rest.find("\n\n").unwrap_or(rest.len());
+    }
+
+    // the window starts at an old sentence, so the sentences before it stay the same
+    let head = old_spans.partition_point(|span| span.start < start);
+    let start = match old_spans.get(head) {
+        Some(span) if head > 0 && span.end <= edit.start => span.start,
+        _ => 0,
+    };
+    let head = if start == 0 { 0 } else { head };
+
+    // the window ends with an old sentence after the edit, so the sentences after it stay the same
+    let after_edit = old_spans.partition_point(|span| span.start < old_edit_end);
+    let last = after_edit + old_spans[after_edit..].partition_point(|span| shift(span).end < end);
+    let (end, tail) = match old_spans.get(last) {
+        Some(span) => (shift(span).end, last + 1),
+        None => (text.len(), old_spans.len()),
+    };
+
+    let window = split_multi_spans(&text[start..end], cfg.clone());
+    let window = window.into_iter().map(|span| span.start + start..span.end + start).collect::<Vec<_>>();
+
+    // unless the edit changed the last sentence of the window, too
+    let in_sync = tail == old_spans.len() || window.last() == Some(&shift(&old_spans[tail - 1]));
+
+    if !in_sync {
+        return split_multi_spans(text, cfg);
+    }
+
+    let mut spans = old_spans[..head].to_vec();
+    spans.extend(window);
+    spans.extend(old_spans[tail..].iter().map(shift));
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "First paragraph. It is short.\n\nSecond one, etc.\n\nand a continuation. Third (one.\n\n\
+                        Two) end.\n\nFourth paragraph here. With Dr. Who.\n\nFifth one. The end.";
+
+    #[test]
+    fn spans() {
+        let text = " One. Two.\n\nThree. ";
+        let spans = split_multi_spans(text, Default::default());
+        assert_eq!(spans, [1..5, 6..10, 12..18]);
+        assert_eq!(spans.iter().map(|span| &text[span.clone()]).collect::<Vec<_>>(), ["One.", "Two.", "Three."]);
+        assert!(split_multi_spans("  ", Default::default()).is_empty());
+        assert_eq!(split_multi_spans("Ask Dr. Who. Then e.g.\nleave.", Default::default()), [0..12, 13..29]);
+    }
+
+    #[test]
+    fn single_spans() {
+        let text = " Compare Fig. 3 here.\n\n  Next\tline. \r\nLast.\n";
+        let spans = split_single_spans(text, Default::default());
+        let sentences = spans.iter().map(|span| &text[span.clone()]).collect::<Vec<_>>();
+        assert_eq!(sentences, split_single(text, Default::default()));
+        assert_eq!(sentences, ["Compare Fig. 3 here.", "Next\tline.", "Last."]);
+
+        let cfg = SegmentConfig::default().inline_math(true).strip_quote_prefix(true);
+        let text = "> Let $x = 1. 2$ be.\n> Then go.";
+        let sentences = split_single_spans(text, cfg).into_iter().map(|span| &text[span]).collect::<Vec<_>>();
+        assert_eq!(sentences, ["> Let $x = 1. 2$ be.", "> Then go."]);
+        assert!(split_single_spans(" \n ", Default::default()).is_empty());
+    }
+
+    #[test]
+    fn range() {
+        let text = "Before. Ünïcode one. Two.\n\nAfter.";
+        let spans = split_multi_range(text, 8..text.len() - 6, Default::default()).unwrap();
+        assert_eq!(spans.iter().map(|span| &text[span.clone()]).collect::<Vec<_>>(), ["Ünïcode one.", "Two."]);
+        assert_eq!(split_multi_range(text, 3..3, Default::default()), Ok(vec![]));
+
+        for range in [9..12, 8..100, Range { start: 12, end: 8 }] {
+            let err = SegmentError::Range { range: range.clone() };
+            assert_eq!(split_multi_range(text, range, Default::default()), Err(err));
+        }
+    }
+
+    fn check(old: &str, edit: Range<usize>, replacement: &str) {
+        let text = [&old[..edit.start], replacement, &old[edit.end..]].concat();
+        let old_spans = split_multi_spans(old, Default::default());
+        let new_edit = edit.start..edit.start + replacement.len();
+
+        let actual = resegment(&text, &old_spans, new_edit, edit.len(), Default::default());
+        assert_eq!(actual, split_multi_spans(&text, Default::default()), "{text:?}");
+    }
+
+    #[test]
+    fn insertions() {
+        for pos in (0..=TEXT.len()).filter(|&pos| TEXT.is_char_boundary(pos)) {
+            for inserted in [" ", ". New", "\n\n", "Mr. ", ")"] {
+                check(TEXT, pos..pos, inserted);
+            }
+        }
+    }
+
+    #[test]
+    fn deletions() {
+        for pos in 0..TEXT.len() {
+            for len in [1, 5, 20] {
+                if pos + len <= TEXT.len() {
+                    check(TEXT, pos..pos + len, "");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn replacements() {
+        check(TEXT, 0..5, "Last");
+        check(TEXT, 32..38, "Another");
+        check(TEXT, TEXT.len() - 8..TEXT.len(), "Then. More.\n\nAnd more.");
+    }
+}
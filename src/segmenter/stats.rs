@@ -0,0 +1,172 @@
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use super::{try_split_multi_iter, Hooks, MergeKind, SegmentConfig};
+
+/// How the bracket heuristic of [split_multi](super::split_multi) decided on a text,
+/// to tune the [short_sentence_length](SegmentConfig::short_sentence_length) for a corpus.
+///
+/// A sentence with an open bracket is joined with the next one if either is shorter than the threshold,
+/// so each candidate is reported with the length of the shorter fragment.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct BracketStats {
+    /// The byte lengths of the shorter fragment of each candidate, in ascending order.
+    pub fragment_lengths: Vec<usize>,
+    /// How many candidates the threshold of the config joined.
+    pub joined: usize,
+    /// How many candidates the threshold of the config split, as both fragments were too long.
+    pub split: usize,
+}
+
+impl BracketStats {
+    /// How many candidates a `threshold` would join, as their shorter fragment is below it.
+    ///
+    /// Only an estimate for other thresholds than the one of the config,
+    /// as a join changes the fragments that come after it.
+    pub fn joined_below(&self, threshold: usize) -> usize {
+        self.fragment_lengths.partition_point(|&len| len < threshold)
+    }
+}
+
+/// Run the segmentation of [split_multi](super::split_multi) in a counting mode, and report
+/// the fragments around open brackets, whose joins depend on the
+/// [short_sentence_length](SegmentConfig::short_sentence_length).
+///
+/// ```rust
+/// use segtok::segmenter::{analyze_brackets, SegmentConfig};
+///
+/// let stats = analyze_brackets("Values (see Fig. 2. Left) differ.", SegmentConfig::default());
+/// assert_eq!((stats.joined, stats.split), (1, 0));
+/// ```
+pub fn analyze_brackets(text: &str, cfg: SegmentConfig) -> BracketStats {
+    let short_sentence_length = cfg.short_sentence_length;
+    let fragment_lengths = RefCell::new(Vec::new());
+    let on_bracket_fragment = |len| fragment_lengths.borrow_mut().push(len);
+    let hooks = Hooks { on_bracket_fragment: Rc::new(on_bracket_fragment), ..Hooks::default() };
+    try_split_multi_iter(Cow::Borrowed(text), cfg, None, hooks).for_each(|sentence| {
+        sentence.unwrap();
+    });
+
+    let mut fragment_lengths = fragment_lengths.into_inner();
+    fragment_lengths.sort_unstable();
+    let joined = fragment_lengths.partition_point(|&len| len < short_sentence_length);
+    let split = fragment_lengths.len() - joined;
+    BracketStats { fragment_lengths, joined, split }
+}
+
+/// How many boundaries and joins of each cause the segmentation of [split_multi](super::split_multi)
+/// made on a text, to profile a corpus and spot anomalies, like an unusual share of abbreviations.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct SegReport {
+    /// The splits within a paragraph, mostly after a sentence terminal.
+    pub terminal_boundaries: usize,
+    /// The splits at a paragraph break, that is, at consecutive newlines.
+    pub paragraph_boundaries: usize,
+    pub total_sentences: usize,
+    /// The spans joined after an abbreviation, an initial, or an outline item, see [MergeKind::Abbreviation].
+    pub joins_abbrev: usize,
+    /// The spans joined as they continue the sentence, see [MergeKind::Continuation]
+    /// and [MergeKind::LowercaseFollow].
+    pub joins_continuation: usize,
+}
+
+/// Run the segmentation of [split_multi](super::split_multi), and count its boundaries and joins by cause.
+///
+/// The joins of the brackets, quotes, and detached terminals, and of the
+/// [min_sentence_tokens](SegmentConfig::min_sentence_tokens) rule are not counted.
+/// A rule line of the [rule_line_is_break](SegmentConfig::rule_line_is_break) counts as a paragraph break.
+///
+/// ```rust
+/// use segtok::segmenter::{segmentation_report, SegmentConfig};
+///
+/// let report = segmentation_report("Ask Dr. Smith. He knows.\n\nBye.", SegmentConfig::default());
+/// assert_eq!((report.total_sentences, report.terminal_boundaries, report.paragraph_boundaries), (3, 1, 1));
+/// assert_eq!((report.joins_abbrev, report.joins_continuation), (1, 0));
+/// ```
+pub fn segmentation_report(text: &str, cfg: SegmentConfig) -> SegReport {
+    let joins = Cell::new((0, 0));
+    let on_merge = |kind, _: &str, _| {
+        let (abbrev, continuation) = joins.get();
+        joins.set(match kind {
+            MergeKind::Abbreviation => (abbrev + 1, continuation),
+            MergeKind::Continuation | MergeKind::LowercaseFollow => (abbrev, continuation + 1),
+            _ => (abbrev, continuation),
+        });
+    };
+
+    let mut report = SegReport::default();
+    let hooks = Hooks { on_merge: Rc::new(on_merge), ..Hooks::default() };
+    for sentence in try_split_multi_iter(Cow::Borrowed(text), cfg, None, hooks) {
+        let (_, paragraph) = sentence.unwrap();
+        match report.total_sentences {
+            0 => {}
+            _ if paragraph => report.paragraph_boundaries += 1,
+            _ => report.terminal_boundaries += 1,
+        }
+        report.total_sentences += 1;
+    }
+
+    (report.joins_abbrev, report.joins_continuation) = joins.get();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "Short (this one. Is joined) here. \
+                        And a long sentence with an open bracket (which goes on for quite a while. \
+                        And then another long fragment follows in the very same bracket). End.";
+
+    #[test]
+    fn stats() {
+        let stats = analyze_brackets(TEXT, SegmentConfig::default());
+        assert_eq!(stats.fragment_lengths.len(), 2);
+        assert_eq!((stats.joined, stats.split), (1, 1));
+        assert_eq!(stats.joined_below(0), 0);
+        assert_eq!(stats.joined_below(1000), 2);
+
+        let threshold = stats.fragment_lengths[1] + 1;
+        let stats = analyze_brackets(TEXT, SegmentConfig::default().short_sentence_length(threshold));
+        assert_eq!((stats.joined, stats.split), (2, 0));
+    }
+
+    #[test]
+    fn report() {
+        let text = "Compare e.g. Fig. 3 with this. and that one. It was the largest city. So it goes.\n\n\
+                    A new paragraph by J. Miller. The mouse m. musculus is small.\n\n\nEnd.";
+        let expected = SegReport {
+            terminal_boundaries: 3,
+            paragraph_boundaries: 2,
+            total_sentences: 6,
+            joins_abbrev: 3,
+            joins_continuation: 2,
+        };
+        assert_eq!(segmentation_report(text, SegmentConfig::default()), expected);
+        assert_eq!(segmentation_report(" ", SegmentConfig::default()), SegReport::default());
+
+        let report = segmentation_report("One.\r\n\r\nTwo.", SegmentConfig::default());
+        assert_eq!((report.total_sentences, report.paragraph_boundaries, report.terminal_boundaries), (2, 1, 0));
+        let text = "Alice: Hi. Bob: Hello.\n\nAlice: Bye.\n---\nThe $x. y$ end. Done.";
+        let cfg = SegmentConfig::default().speaker_labels(true).rule_line_is_break(true).inline_math(true);
+        let report = segmentation_report(text, cfg.clone());
+        assert_eq!(report.total_sentences, crate::segmenter::split_multi(text, cfg).len());
+        assert_eq!((report.paragraph_boundaries, report.terminal_boundaries), (2, 2));
+    }
+
+    #[test]
+    fn pipeline() {
+        // the turns split the bracket, and the dot inside the math is masked
+        let text = "Bob: See (the Fig. Alice: Right.) Then (see $a. B$ here) go.\r\n\r\nNew (one. Two) end.";
+        let cfg = SegmentConfig::default().speaker_labels(true).inline_math(true);
+        let stats = analyze_brackets(text, cfg);
+        assert_eq!(stats.fragment_lengths, ["Two) end.".len()]);
+        assert_eq!((stats.joined, stats.split), (1, 0));
+    }
+
+    #[test]
+    fn no_brackets() {
+        assert_eq!(analyze_brackets("One. Two.", SegmentConfig::default()), BracketStats::default());
+    }
+}
@@ -0,0 +1,50 @@
+use super::{split_multi, SegmentConfig};
+
+/// The same as [split_multi], but splits off the terminal of each sentence, so the sentences come without
+/// terminals, and the terminals come in a second list of the same length.
+///
+/// A terminal is the sequence of terminal chars at the end of a sentence, as in "." or "?!",
+/// together with the closing quotes and brackets after it, as in `."` or `!)`.
+/// A sentence without a terminal, as before a paragraph break, has an empty terminal.
+/// Joining each sentence with its terminal restores the sentences of [split_multi].
+pub fn split_multi_split_terminals(text: &str, cfg: SegmentConfig) -> (Vec<String>, Vec<String>) {
+    let is_terminal = |ch: char| cfg.is_terminal(ch);
+    let is_closing = |ch: char| cfg.is_closing(ch);
+
+    split_multi(text, cfg.clone())
+        .into_iter()
+        .map(|mut sentence| {
+            let closed = sentence.trim_end_matches(is_closing);
+            let stripped = closed.trim_end_matches(is_terminal);
+            let pos = if stripped.len() == closed.len() { sentence.len() } else { stripped.len() };
+            let terminal = sentence.split_off(pos);
+            (sentence, terminal)
+        })
+        .unzip()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::segmenter::split_multi_with_gaps;
+
+    #[test]
+    fn terminals() {
+        let text = "This is a test. Is it?! He said \"Go.\" (Really.)\n\nNo terminal\n\nWait...";
+        let (sentences, terminals) = split_multi_split_terminals(text, Default::default());
+        assert_eq!(sentences, ["This is a test", "Is it", "He said \"Go", "(Really", "No terminal", "Wait"]);
+        assert_eq!(terminals, [".", "?!", ".\"", ".)", "", "..."]);
+
+        // spliced back with the separators between the sentences, they restore the text
+        let gaps = split_multi_with_gaps(text, Default::default());
+        let restored = sentences.iter().zip(&terminals).zip(&gaps).map(|((s, t), (_, gap))| format!("{s}{t}{gap}"));
+        assert_eq!(restored.collect::<String>(), text);
+    }
+
+    #[test]
+    fn quotes_without_terminal() {
+        let (sentences, terminals) = split_multi_split_terminals("He said \"go\"", Default::default());
+        assert_eq!(sentences, ["He said \"go\""]);
+        assert_eq!(terminals, [""]);
+    }
+}
@@ -4,17 +4,70 @@ use std::sync::LazyLock;
 use fancy_regex::Regex;
 
 /// All linebreak sequence variants except the Unix newline (only).
-#[deprecated]
 pub static NON_UNIX_LINEBREAK: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"\r\n|\r|\u{2028}"#).unwrap());
 
 /// Replace non-Unix linebreak sequences (Windows, Mac, Unicode) with newlines (`\n`).
-#[deprecated]
-#[allow(deprecated)]
-pub fn to_unix_linebreaks(text: &str) -> Cow<str> {
+///
+/// The [split_single](super::split_single) and [split_multi](super::split_multi) do it on their own.
+pub fn to_unix_linebreaks(text: &str) -> Cow<'_, str> {
     NON_UNIX_LINEBREAK.replace_all(text, "\n")
 }
 
-#[allow(deprecated)]
+/// The [to_unix_linebreaks] before segmenting, which borrows the text if it has only Unix newlines.
+pub(crate) fn normalize_linebreaks(text: &str) -> Cow<'_, str> {
+    if text.contains(['\r', '\u{2028}']) {
+        to_unix_linebreaks(text)
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// The byte offsets of the text after the [to_unix_linebreaks], to take the sentences of the normalized text
+/// from the text, with its own linebreaks, one after the other.
+pub(crate) struct LinebreakOffsets {
+    /// The offsets after each non-Unix linebreak, in the normalized text and in the text.
+    breaks: Vec<(usize, usize)>,
+    cursor: usize,
+}
+
+impl LinebreakOffsets {
+    pub(crate) fn new(text: &str) -> Self {
+        let mut breaks = vec![(0, 0)];
+        let mut removed = 0;
+
+        for linebreak in NON_UNIX_LINEBREAK.find_iter(text).map(Result::unwrap) {
+            removed += linebreak.as_str().len() - 1;
+            breaks.push((linebreak.end() - removed, linebreak.end()));
+        }
+
+        Self { breaks, cursor: 0 }
+    }
+
+    /// The offset in the text of an `offset` in the normalized text.
+    pub(crate) fn original(&self, offset: usize) -> usize {
+        let idx = self.breaks.partition_point(|&(normalized, _)| normalized <= offset) - 1;
+        let (normalized, original) = self.breaks[idx];
+        original + offset - normalized
+    }
+
+    /// Take the trimmed `sentence` of the normalized text, the next one after the last one taken, from the `text`.
+    ///
+    /// The linebreaks are whitespace in both texts, so the sentences are separated by the same whitespace.
+    pub(crate) fn take<'t>(&mut self, text: &'t str, sentence: &str) -> &'t str {
+        let rest = &text[self.cursor..];
+        let start = self.cursor + rest.len() - rest.trim_start().len();
+
+        let idx = self.breaks.partition_point(|&(_, offset)| offset <= start) - 1;
+        let (normalized, offset) = self.breaks[idx];
+        let end = normalized + start - offset + sentence.len();
+
+        let idx = self.breaks.partition_point(|&(normalized, _)| normalized <= end) - 1;
+        let (normalized, offset) = self.breaks[idx];
+        self.cursor = offset + end - normalized;
+        &text[start..self.cursor]
+    }
+}
+
 #[allow(non_snake_case)]
 #[cfg(test)]
 mod tests {
@@ -39,4 +92,20 @@ mod tests {
             assert!(!NON_UNIX_LINEBREAK.is_match(example).unwrap());
         }
     }
+
+    #[test]
+    fn normalize() {
+        let text = "One\r\ntwo\rthree\u{2028}four\n";
+        assert_eq!(normalize_linebreaks(text), "One\ntwo\nthree\nfour\n");
+        assert!(matches!(normalize_linebreaks("One\ntwo"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn offsets() {
+        let text = " One\r\n\r\ntwo\rthree\u{2028}\u{2028}four. \r\n";
+        let mut offsets = LinebreakOffsets::new(text);
+        assert_eq!(offsets.take(text, "One"), "One");
+        assert_eq!(offsets.take(text, "two\nthree"), "two\rthree");
+        assert_eq!(offsets.take(text, "four."), "four.");
+    }
 }
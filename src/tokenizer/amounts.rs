@@ -0,0 +1,68 @@
+use std::sync::LazyLock;
+
+use fancy_regex::Regex;
+
+/// A number, optionally with thousands separators and decimals, like "1,000.00", "1.000,00", or "1000".
+pub static AMOUNT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^(?:\p{Nd}{1,3}(?:([,.])\p{Nd}{3})(?:\1\p{Nd}{3})*|\p{Nd}+)(?:[.,]\p{Nd}+)?$"#).unwrap()
+});
+
+/// A currency symbol, like "$" or "€", or a three-letter currency code, like "USD".
+pub static CURRENCY: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^(?:\p{Sc}|\p{Lu}{3})$"#).unwrap());
+
+/// Check if a pair of tokens is a monetary amount, with the currency either first or second:
+/// "$" and "1,000.00", "1.000,00" and "€", or "USD" and "1,000".
+///
+/// The tokenizers never join such pairs, so apply it to consecutive tokens to tag amounts:
+///
+/// ```rust
+/// use itertools::Itertools;
+/// use segtok::tokenizer::{is_amount, word_tokenizer};
+///
+/// let tokens = word_tokenizer("Pay USD 1,000 now.");
+/// let amounts = tokens.iter().tuple_windows().filter(|&(a, b)| is_amount((a, b))).collect::<Vec<_>>();
+/// assert_eq!(amounts, [(&"USD".to_owned(), &"1,000".to_owned())]);
+/// ```
+pub fn is_amount<S: AsRef<str>>(pair: (S, S)) -> bool {
+    let (first, second) = (pair.0.as_ref(), pair.1.as_ref());
+    let is_amount = |token: &str| AMOUNT.is_match(token).unwrap();
+    let is_currency = |token: &str| CURRENCY.is_match(token).unwrap();
+
+    is_currency(first) && is_amount(second) || is_amount(first) && is_currency(second)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::word_tokenizer;
+
+    #[test]
+    fn amounts() {
+        for amount in ["1", "1000", "1,000", "1,000.00", "1.000,00", "1,000,000.50", "0.5"] {
+            assert!(AMOUNT.is_match(amount).unwrap(), "{amount}");
+        }
+
+        for amount in ["1,00,0", "1.000,000,00", "1,000.000.00", "a1", "1."] {
+            assert!(!AMOUNT.is_match(amount).unwrap(), "{amount}");
+        }
+    }
+
+    #[test]
+    fn pairs() {
+        assert!(is_amount(("$", "1,000.00")));
+        assert!(is_amount(("1.000,00", "€")));
+        assert!(is_amount(("USD", "1,000")));
+        assert!(is_amount(("1,000", "USD")));
+        assert!(!is_amount(("costs", "1,000")));
+        assert!(!is_amount(("USD", "EUR")));
+        assert!(!is_amount(("Usd", "1")));
+    }
+
+    #[test]
+    fn tokenized() {
+        assert_eq!(word_tokenizer("It costs $1,000.00."), ["It", "costs", "$", "1,000.00", "."]);
+        assert_eq!(word_tokenizer("It costs 1.000,00 €."), ["It", "costs", "1.000,00", "€", "."]);
+        assert_eq!(word_tokenizer("It costs 1.000,00€."), ["It", "costs", "1.000,00", "€", "."]);
+        assert_eq!(word_tokenizer("It costs USD 1,000."), ["It", "costs", "USD", "1,000", "."]);
+    }
+}
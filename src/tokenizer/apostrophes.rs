@@ -0,0 +1,132 @@
+use super::{IS_CONTRACTION, IS_POSSESSIVE};
+
+/// All apostrophe-like marks, including the ASCII "single quote" and the "prime",
+/// which the tokenizers and the contraction and possessive splitters treat alike,
+/// so "won't", "won’t", and "won´t" are split the same way.
+///
+/// The Hawaiian ʻokina (U+02BB) is not one of them: it is a modifier letter,
+/// so words like "Hawaiʻi" or "ʻukulele" are never split at it.
+pub const APOSTROPHE_CHARS: &[char] = &['\'', '\u{00B4}', '\u{02B9}', '\u{02BC}', '\u{2019}', '\u{2032}'];
+
+/// Check if the char is one of the [APOSTROPHE_CHARS].
+pub fn is_apostrophe(ch: char) -> bool {
+    APOSTROPHE_CHARS.contains(&ch)
+}
+
+/// The role an apostrophe-like mark plays inside a token.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum ApostropheRole {
+    /// A contraction ("don't", "we'll") or any other elision inside a word ("O'Neil").
+    Contraction,
+    /// A possessive marker ("Fred's", "Charles'").
+    Possessive,
+    /// A quote at the start of the token.
+    OpeningQuote,
+    /// A quote at the end of the token.
+    ClosingQuote,
+    /// A prime after a number, as in "5'-ACGT" or "5′".
+    Prime,
+}
+
+/// Decide what the apostrophe at the byte offset `pos` of the `token` is used for.
+///
+/// Returns `None` if there is no apostrophe-like char at `pos`.
+/// As "'s" can be both a contraction and a possessive marker, it is always reported as the latter.
+pub fn classify_apostrophe(token: &str, pos: usize) -> Option<ApostropheRole> {
+    let ch = token.get(pos..)?.chars().next().filter(|&ch| is_apostrophe(ch))?;
+    let before = token[..pos].chars().next_back();
+    let after = &token[pos + ch.len_utf8()..];
+    let is_last = !after.chars().any(is_apostrophe);
+
+    let role = if before.is_some_and(char::is_numeric) && !after.starts_with(char::is_alphabetic) {
+        ApostropheRole::Prime
+    } else if is_last && IS_POSSESSIVE.is_match(token).unwrap() {
+        ApostropheRole::Possessive
+    } else if is_last && IS_CONTRACTION.is_match(token).unwrap() {
+        ApostropheRole::Contraction
+    } else if before.is_none() {
+        ApostropheRole::OpeningQuote
+    } else if after.is_empty() {
+        ApostropheRole::ClosingQuote
+    } else {
+        ApostropheRole::Contraction
+    };
+
+    Some(role)
+}
+
+#[cfg(test)]
+mod tests {
+    use fancy_regex::Regex;
+
+    use super::*;
+    use crate::tokenizer::{
+        is_non_quote_apostrophe, split_contractions, word_tokenizer, APOSTROPHES, NON_QUOTE_APOSTROPHE,
+    };
+
+    fn classify_all(token: &str) -> Vec<ApostropheRole> {
+        token.char_indices().filter_map(|(pos, _)| classify_apostrophe(token, pos)).collect()
+    }
+
+    #[test]
+    fn classes() {
+        let apostrophes = Regex::new(&format!("^{APOSTROPHES}$")).unwrap();
+        let non_quote = Regex::new(&format!("^{}$", *NON_QUOTE_APOSTROPHE)).unwrap();
+        for ch in ['\'', 'ʻ', '"', '`', 'a'].into_iter().chain(APOSTROPHE_CHARS.iter().copied()) {
+            assert_eq!(apostrophes.is_match(&ch.to_string()).unwrap(), is_apostrophe(ch), "{ch}");
+            assert_eq!(non_quote.is_match(&ch.to_string()).unwrap(), is_non_quote_apostrophe(ch), "{ch}");
+        }
+
+        #[allow(deprecated)]
+        let list = crate::tokenizer::LIST_OF_APOSTROPHES;
+        assert_eq!(list.chars().collect::<Vec<_>>(), APOSTROPHE_CHARS);
+    }
+
+    #[test]
+    fn same_splits() {
+        for &ap in APOSTROPHE_CHARS {
+            let tokens = split_contractions(word_tokenizer(&format!("I won{ap}t go, we{ap}ll see.")));
+            let expected = ["I", "wo", &format!("n{ap}t"), "go", ",", "we", &format!("{ap}ll"), "see", "."];
+            assert_eq!(tokens, expected, "{ap}");
+        }
+    }
+
+    #[test]
+    fn not_an_apostrophe() {
+        assert_eq!(classify_apostrophe("don't", 0), None);
+        assert_eq!(classify_apostrophe("don't", 10), None);
+    }
+
+    #[test]
+    fn contractions() {
+        assert_eq!(classify_all("don't"), [ApostropheRole::Contraction]);
+        assert_eq!(classify_all("we\u{2019}ll"), [ApostropheRole::Contraction]);
+        assert_eq!(classify_all("O'Neil"), [ApostropheRole::Contraction]);
+    }
+
+    #[test]
+    fn possessives() {
+        assert_eq!(classify_all("Fred's"), [ApostropheRole::Possessive]);
+        assert_eq!(classify_all("Charles\u{2019}"), [ApostropheRole::Possessive]);
+    }
+
+    #[test]
+    fn primes() {
+        assert_eq!(classify_all("5\u{2032}"), [ApostropheRole::Prime]);
+        assert_eq!(classify_all("5'-"), [ApostropheRole::Prime]);
+    }
+
+    #[test]
+    fn quotes() {
+        let tokens = word_tokenizer("He said, 'this.'");
+        let roles = tokens.iter().flat_map(|token| classify_all(token)).collect::<Vec<_>>();
+        assert_eq!(roles, [ApostropheRole::OpeningQuote, ApostropheRole::OpeningQuote]);
+        assert_eq!(classify_all("that'"), [ApostropheRole::ClosingQuote]);
+    }
+
+    #[test]
+    fn multiple() {
+        // see: https://github.com/fnl/segtok/issues/26
+        assert_eq!(classify_all("OʼHaraʼs"), [ApostropheRole::Contraction, ApostropheRole::Possessive]);
+    }
+}
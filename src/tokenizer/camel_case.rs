@@ -0,0 +1,87 @@
+/// A function to split run-together words at case boundaries, as in camelCase identifiers or OCR errors.
+///
+/// Takes the output of any of the tokenizer functions and produces an updated list:
+///
+/// ```rust
+/// use segtok::tokenizer::{split_camel_case, word_tokenizer};
+///
+/// assert_eq!(
+///     split_camel_case(word_tokenizer("The HTTPServer said HelloWorld.")),
+///     ["The", "HTTP", "Server", "said", "Hello", "World", "."]
+/// );
+/// ```
+///
+/// A token is split before an upper-case letter after a lower-case one ("endOf"),
+/// and before the last letter of an upper-case acronym if a lower-case letter follows ("HTTPServer"),
+/// except for a plural "s" ("URLs").
+pub fn split_camel_case(tokens: Vec<String>) -> Vec<String> {
+    let mut res = Vec::with_capacity(tokens.len());
+
+    for token in tokens {
+        let mut start = 0;
+        for pos in case_boundaries(&token) {
+            res.push(token[start..pos].to_owned());
+            start = pos;
+        }
+
+        if start == 0 {
+            res.push(token);
+        } else {
+            res.push(token[start..].to_owned());
+        }
+    }
+
+    res
+}
+
+/// The byte positions where a new word starts inside the token.
+fn case_boundaries(token: &str) -> Vec<usize> {
+    let chars = token.char_indices().collect::<Vec<_>>();
+    let mut res = Vec::new();
+
+    for (idx, &(pos, ch)) in chars.iter().enumerate().skip(1) {
+        let prev = chars[idx - 1].1;
+        let next = chars.get(idx + 1).map(|&(_, next)| next);
+        let is_plural = next == Some('s') && chars.get(idx + 2).is_none_or(|&(_, after)| !after.is_lowercase());
+
+        if prev.is_lowercase() && ch.is_uppercase()
+            || prev.is_uppercase() && ch.is_uppercase() && next.is_some_and(char::is_lowercase) && !is_plural
+        {
+            res.push(pos);
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(token: &str) -> Vec<String> {
+        split_camel_case(vec![token.to_owned()])
+    }
+
+    #[test]
+    fn lower_to_upper() {
+        assert_eq!(split("HelloWorld"), ["Hello", "World"]);
+        assert_eq!(split("endOf"), ["end", "Of"]);
+        assert_eq!(split("aBcD"), ["a", "Bc", "D"]);
+        assert_eq!(split("ÜberÄrger"), ["Über", "Ärger"]);
+    }
+
+    #[test]
+    fn acronyms() {
+        assert_eq!(split("HTTPServer"), ["HTTP", "Server"]);
+        assert_eq!(split("parseXMLFile"), ["parse", "XML", "File"]);
+        assert_eq!(split("getURLs"), ["get", "URLs"]);
+        assert_eq!(split("URLsAndMore"), ["URLs", "And", "More"]);
+    }
+
+    #[test]
+    fn misses() {
+        for token in ["Hello", "HTTP", "URLs", "CPUs'", "x", "", "snake_case", "COVID-19", "."] {
+            assert_eq!(split(token), [token]);
+        }
+    }
+}
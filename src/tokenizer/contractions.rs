@@ -1,17 +1,48 @@
+use std::borrow::Cow;
+use std::iter::{once, successors};
 use std::sync::LazyLock;
 
+use either::Either;
 use fancy_regex::Regex;
+use itertools::Itertools;
 
-use super::{is_apostrophe, ALPHA_NUM, APOSTROPHES, HYPHEN};
+use super::{is_apostrophe, Token, TokenizeConfig, ALPHA_NUM, APOSTROPHES, HYPHEN};
 
 /// A pattern that matches tokens with valid English contractions ``'(d|ll|m|re|s|t|ve)``.
+///
+/// The word before the contraction may contain apostrophes itself, as in "y'all'd" or "shouldn't've".
 pub static IS_CONTRACTION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r#"^{ALPHA_NUM}+(?:(?:{HYPHEN}|{APOSTROPHES}){ALPHA_NUM}+)*{APOSTROPHES}(?:d|ll|m|re|s|t|ve)$"#
+    ))
+    .unwrap()
+});
+
+/// The [IS_CONTRACTION] of the python package, without apostrophes in the word before the contraction.
+static PYTHON_CONTRACTION: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(&format!(r#"^{ALPHA_NUM}+(?:{HYPHEN}{ALPHA_NUM}+)*{APOSTROPHES}(?:d|ll|m|re|s|t|ve)$"#)).unwrap()
 });
 
+/// Where [split_contractions_with] splits the negative contractions of English, see
+/// [TokenizeConfig::contraction_style].
+///
+/// Only the "n't" family is affected, like "don't", "can't", "won't", "isn't", or the "n't" of "shouldn't've".
+/// The other contractions, "'d", "'ll", "'m", "'re", "'s", and "'ve", are split before the apostrophe either way.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum ContractionStyle {
+    /// The Penn Treebank style: "n't" is split off with the "n", so "don't" yields "do" and "n't",
+    /// and "won't" yields "wo" and "n't".
+    #[default]
+    Penn,
+    /// The stem is kept intact and only the apostrophe is split off, so "don't" yields "don" and "'t",
+    /// like "We'll" yields "We" and "'ll".
+    Attached,
+}
+
 /// A function to split apostrophe contractions at the end of alphanumeric (and hyphenated) tokens.
 ///
 /// Takes the output of a tokenizer function and produces an updated list.
+/// Chained contractions are split one by one: "shouldn't've" becomes "should", "n't", and "'ve".
 ///
 /// **Note**: the original implementation contains a [bug](https://github.com/fnl/segtok/issues/26)
 /// where multiple substrings were produced.
@@ -20,31 +51,151 @@ pub static IS_CONTRACTION: LazyLock<Regex> = LazyLock::new(|| {
 /// split_contractions(word_tokenizer("OʼHaraʼs"))
 /// <<< ['OʼHara', 'O', 'ʼHaraʼs']
 /// ```
-pub fn split_contractions(mut tokens: Vec<String>) -> Vec<String> {
+pub fn split_contractions(tokens: Vec<String>) -> Vec<String> {
+    split_contractions_styled(tokens, ContractionStyle::Penn)
+}
+
+fn split_contractions_styled(mut tokens: Vec<String>, style: ContractionStyle) -> Vec<String> {
     let mut idx = 0;
 
     while idx < tokens.len() {
         let token = &mut tokens[idx];
 
-        if token.len() > 1 && IS_CONTRACTION.is_match(token).unwrap() {
-            if let Some((mut pos, ap)) = token.char_indices().rfind(|&(_, ch)| is_apostrophe(ch)) {
-                // don't, doesn't
-                if token.get(pos.saturating_sub(1)..pos) == Some("n") && token.get(pos + ap.len_utf8()..) == Some("t") {
-                    pos = pos.saturating_sub(1);
-                }
+        // the remaining head is checked again, so chained contractions are split, too
+        if let Some(pos) = contraction_split_point(token, style) {
+            let suffix = token.split_off(pos);
+            tokens.insert(idx + 1, suffix);
+        } else {
+            idx += 1;
+        }
+    }
+
+    tokens
+}
+
+/// The same as [split_contractions], but borrows the tokens and yields the parts lazily,
+/// so a streaming pipeline does not have to build a new list.
+///
+/// The parts are slices of the tokens, so the split ones are not copied either.
+///
+/// ```rust
+/// use segtok::tokenizer::split_contractions_iter;
+///
+/// let tokens = ["I", "don't", "know"].map(String::from);
+/// let parts = split_contractions_iter(&tokens).collect::<Vec<_>>();
+/// assert_eq!(parts, ["I", "do", "n't", "know"]);
+/// ```
+pub fn split_contractions_iter(tokens: &[String]) -> impl Iterator<Item = Cow<'_, str>> {
+    tokens.iter().flat_map(|token| contraction_parts(token)).map(Cow::Borrowed)
+}
+
+/// The parts of the `token` split at its (chained) contractions, in order.
+fn contraction_parts(token: &str) -> impl Iterator<Item = &str> {
+    let Some(pos) = contraction_split_point(token, ContractionStyle::Penn) else { return Either::Left(once(token)) };
 
-                let suffix = token.split_off(pos);
-                idx += 1;
-                tokens.insert(idx, suffix);
+    // the split points are found from the end, as the remaining head is checked again
+    let mut bounds = successors(Some(pos), |&end| contraction_split_point(&token[..end], ContractionStyle::Penn))
+        .collect::<Vec<_>>();
+    bounds.reverse();
+    let parts = once(0).chain(bounds).chain(once(token.len())).tuple_windows().map(|(start, end)| &token[start..end]);
+    Either::Right(parts)
+}
+
+/// The [split_contractions] with the options of a [TokenizeConfig], like the
+/// [contraction_style](TokenizeConfig::contraction_style):
+///
+/// ```rust
+/// use segtok::tokenizer::{split_contractions_with, ContractionStyle, TokenizeConfig};
+///
+/// let cfg = TokenizeConfig::default().contraction_style(ContractionStyle::Attached);
+/// let tokens = split_contractions_with(vec!["We'll".to_owned(), "don't".to_owned()], cfg);
+/// assert_eq!(tokens, ["We", "'ll", "don", "'t"]);
+/// ```
+///
+/// With the [python_compat](TokenizeConfig::python_compat) mode, the bugs of the python package are reproduced:
+///
+/// ```rust
+/// use segtok::tokenizer::{split_contractions_with, TokenizeConfig};
+///
+/// let cfg = TokenizeConfig::default().python_compat(true);
+/// let tokens = split_contractions_with(vec!["OʼHaraʼs".to_owned(), "shouldn't've".to_owned()], cfg);
+/// assert_eq!(tokens, ["OʼHara", "O", "ʼHaraʼs", "shouldn't've"]);
+/// ```
+pub fn split_contractions_with(tokens: Vec<String>, cfg: TokenizeConfig) -> Vec<String> {
+    if !cfg.python_compat {
+        return split_contractions_styled(tokens, cfg.contraction_style);
+    }
+
+    let mut res = Vec::with_capacity(tokens.len());
+
+    for token in tokens {
+        if token.chars().nth(1).is_none() || !PYTHON_CONTRACTION.is_match(&token).unwrap() {
+            res.push(token);
+            continue;
+        }
+
+        // each apostrophe splits the whole token once more, instead of its remaining head
+        let mut tail = None;
+        for (mut pos, ap) in token.char_indices().rev().filter(|&(_, ch)| is_apostrophe(ch)) {
+            if cfg.contraction_style == ContractionStyle::Penn
+                && token.get(pos.saturating_sub(1)..pos) == Some("n")
+                && token.get(pos + ap.len_utf8()..) == Some("t")
+            {
+                pos -= 1;
             }
+            res.push(token[..pos].to_owned());
+            tail = Some(token[pos..].to_owned());
         }
+        res.extend(tail);
+    }
 
-        idx += 1;
+    res
+}
+
+/// The same as [split_contractions], but keeps track of the byte ranges.
+///
+/// The ranges of both parts are computed within the range of the original token,
+/// so "don't" at `10..15` becomes "do" at `10..12` and "n't" at `12..15`.
+pub fn split_contractions_spans(mut tokens: Vec<Token>) -> Vec<Token> {
+    let mut idx = 0;
+
+    while idx < tokens.len() {
+        let token = &mut tokens[idx];
+
+        if let Some(pos) = contraction_split_point(&token.text, ContractionStyle::Penn) {
+            let suffix = token.text.split_off(pos);
+            let start = token.span.start + pos;
+            let end = token.span.end;
+            token.span.end = start;
+            tokens.insert(idx + 1, Token::new(suffix, start..end));
+        } else {
+            idx += 1;
+        }
     }
 
     tokens
 }
 
+/// Byte offset where the contraction suffix of the `token` starts, if any.
+fn contraction_split_point(token: &str, style: ContractionStyle) -> Option<usize> {
+    if token.len() <= 1 || !IS_CONTRACTION.is_match(token).unwrap() {
+        return None;
+    }
+
+    let (pos, ap) = token.char_indices().rfind(|&(_, ch)| is_apostrophe(ch))?;
+
+    // don't, doesn't, but not a lone "n't"
+    if token.get(pos.saturating_sub(1)..pos) == Some("n") && token.get(pos + ap.len_utf8()..) == Some("t") {
+        let split = match style {
+            ContractionStyle::Penn => pos - 1,
+            ContractionStyle::Attached => pos,
+        };
+        return Some(split).filter(|_| pos > 1);
+    }
+
+    Some(pos)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,10 +247,90 @@ mod tests {
         assert_eq!(res, ["a", "\u{2032}d"]);
     }
 
+    #[test]
+    fn split_okina() {
+        assert!(!IS_CONTRACTION.is_match("Hawai\u{02BB}i").unwrap());
+
+        let tokens = ["Hawai\u{02BB}i", "\u{02BB}ukulele", "Hawai\u{02BB}i's"];
+        let res = split_contractions(tokens.map(ToOwned::to_owned).to_vec());
+        assert_eq!(res, ["Hawai\u{02BB}i", "\u{02BB}ukulele", "Hawai\u{02BB}i", "'s"]);
+    }
+
     #[test]
     fn split_multiple() {
         // see: https://github.com/fnl/segtok/issues/26
         let res = split_contractions(vec!["OʼHaraʼs".to_owned()]);
         assert_eq!(res, ["OʼHara", "ʼs"]);
     }
+
+    #[test]
+    fn split_chains() {
+        let res = split_contractions(vec!["shouldn't've".to_owned()]);
+        assert_eq!(res, ["should", "n't", "'ve"]);
+
+        let res = split_contractions(vec!["n't".to_owned(), "'ve".to_owned()]);
+        assert_eq!(res, ["n't", "'ve"]);
+
+        let res = split_contractions(vec!["y'all'd've".to_owned(), "O'Neil's".to_owned()]);
+        assert_eq!(res, ["y'all", "'d", "'ve", "O'Neil", "'s"]);
+    }
+
+    #[test]
+    fn split_iter() {
+        let tokens = ["We'll", "see", "don't", "won’t", "shouldn't've", "n't", "OʼHaraʼs", "Hawai\u{02BB}i", "!"];
+        let tokens = tokens.map(ToOwned::to_owned);
+        let parts = split_contractions_iter(&tokens).collect::<Vec<_>>();
+        assert_eq!(parts, split_contractions(tokens.to_vec()));
+        assert!(parts.iter().all(|part| matches!(part, Cow::Borrowed(_))));
+        assert_eq!(split_contractions_iter(&[]).count(), 0);
+    }
+
+    #[test]
+    fn split_attached() {
+        let cfg = TokenizeConfig::default().contraction_style(ContractionStyle::Attached);
+        let split = |tokens: &[&str], cfg| split_contractions_with(tokens.iter().map(|&t| t.to_owned()).collect(), cfg);
+
+        let tokens = ["We'll", "don't", "won’t", "can't", "shouldn't've", "n't", "n’t", "I'm"];
+        let expected =
+            ["We", "'ll", "don", "'t", "won", "’t", "can", "'t", "shouldn", "'t", "'ve", "n't", "n’t", "I", "'m"];
+        assert_eq!(split(&tokens, cfg), expected);
+        let penn =
+            ["We", "'ll", "do", "n't", "wo", "n’t", "ca", "n't", "should", "n't", "'ve", "n't", "n’t", "I", "'m"];
+        assert_eq!(split(&tokens, TokenizeConfig::default()), penn);
+        assert_eq!(split(&["don't"], cfg.python_compat(true)), ["don", "'t"]);
+    }
+
+    #[test]
+    fn split_python_compat() {
+        let cfg = TokenizeConfig::default().python_compat(true);
+        let split = |tokens: &[&str]| split_contractions_with(tokens.iter().map(|&t| t.to_owned()).collect(), cfg);
+
+        assert_eq!(split(&["We'll", "see", "don't", "!"]), ["We", "'ll", "see", "do", "n't", "!"]);
+        assert_eq!(split(&["won’t", "a\u{2032}d"]), ["wo", "n’t", "a", "\u{2032}d"]);
+        assert_eq!(split(&["OʼHaraʼs"]), ["OʼHara", "O", "ʼHaraʼs"]);
+        assert_eq!(split(&["shouldn't've", "y'all'd"]), ["shouldn't've", "y'all'd"]);
+        assert_eq!(split(&["n't", "'ve"]), ["", "n't", "'ve"]);
+    }
+
+    #[test]
+    fn split_spans() {
+        let text = "We don’t know OʼHaraʼs plan, y’all’d’ve";
+        let tokens = text
+            .split(' ')
+            .map(|word| {
+                let start = word.as_ptr() as usize - text.as_ptr() as usize;
+                Token::new(word, start..start + word.len())
+            })
+            .collect();
+
+        let res = split_contractions_spans(tokens);
+        let words = res.iter().map(|t| t.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(words, ["We", "do", "n’t", "know", "OʼHara", "ʼs", "plan,", "y’all", "’d", "’ve"]);
+        assert_eq!(res[1].span, 3..5);
+        assert_eq!(res[2].span, 5..10);
+
+        for token in res {
+            assert_eq!(&text[token.span], token.text);
+        }
+    }
 }
@@ -0,0 +1,106 @@
+use std::borrow::Cow;
+
+/// The canonical dash that [normalize_dashes] maps the dash family to.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum DashStyle {
+    /// The ASCII hyphen-minus "-" (U+002D).
+    #[default]
+    HyphenMinus,
+    /// The Unicode hyphen "‐" (U+2010).
+    Hyphen,
+    /// The en dash "–" (U+2013).
+    EnDash,
+    /// The em dash "—" (U+2014).
+    EmDash,
+}
+
+impl DashStyle {
+    pub fn as_char(self) -> char {
+        match self {
+            DashStyle::HyphenMinus => '-',
+            DashStyle::Hyphen => '\u{2010}',
+            DashStyle::EnDash => '\u{2013}',
+            DashStyle::EmDash => '\u{2014}',
+        }
+    }
+}
+
+/// The dash family: the [HYPHENS](crate::segmenter::HYPHENS), the en and em dashes, and the minus sign.
+const DASHES: [char; 15] = [
+    '-', '\u{00AD}', '\u{058A}', '\u{05BE}', '\u{0F0C}', '\u{1400}', '\u{1806}', '\u{2010}', '\u{2011}', '\u{2012}',
+    '\u{2013}', '\u{2014}', '\u{2212}', '\u{2E17}', '\u{30A0}',
+];
+
+/// Check if the char is one of the dashes that [normalize_dashes] maps.
+pub fn is_dash(ch: char) -> bool {
+    DASHES.contains(&ch)
+}
+
+/// Replace every [dash](is_dash) in the `text` with the canonical char of the `style`,
+/// so hyphenated words are joined the same way no matter which dash the text used.
+///
+/// The mapped dashes are:
+/// - the hyphen-minus "-" (U+002D), hyphen (U+2010), and non-breaking hyphen (U+2011),
+/// - the figure dash (U+2012), en dash (U+2013), em dash (U+2014), and minus sign (U+2212),
+/// - the soft hyphen (U+00AD), which becomes visible,
+/// - the Armenian hyphen (U+058A), Hebrew maqaf (U+05BE), Tibetan non-breaking tsheg (U+0F0C),
+///   Canadian syllabics hyphen (U+1400), Mongolian todo soft hyphen (U+1806),
+///   double oblique hyphen (U+2E17), and Katakana-Hiragana double hyphen (U+30A0).
+///
+/// The horizontal bar (U+2015) and the small em dash (U+FE58) are left as they are.
+/// The text is only copied if it contains a dash other than the canonical one.
+///
+/// ```rust
+/// use segtok::tokenizer::{normalize_dashes, DashStyle};
+///
+/// assert_eq!(normalize_dashes("1990–2000 — well‑known", DashStyle::HyphenMinus), "1990-2000 - well-known");
+/// ```
+pub fn normalize_dashes(text: &str, style: DashStyle) -> Cow<'_, str> {
+    let canonical = style.as_char();
+    if text.chars().all(|ch| ch == canonical || !is_dash(ch)) {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(text.chars().map(|ch| if is_dash(ch) { canonical } else { ch }).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hyphen_minus() {
+        let examples = [
+            ("well\u{2011}known", "well-known"),
+            ("pp. 12\u{2013}34", "pp. 12-34"),
+            ("wait\u{2014}what", "wait-what"),
+            ("\u{2212}5 \u{00B0}C", "-5 \u{00B0}C"),
+            ("555\u{2012}0199", "555-0199"),
+            ("co\u{2010}operate", "co-operate"),
+        ];
+        for (text, expected) in examples {
+            assert_eq!(normalize_dashes(text, DashStyle::HyphenMinus), expected);
+        }
+    }
+
+    #[test]
+    fn other_styles() {
+        assert_eq!(normalize_dashes("a-b\u{2014}c", DashStyle::EnDash), "a\u{2013}b\u{2013}c");
+        assert_eq!(normalize_dashes("a-b\u{2013}c", DashStyle::EmDash), "a\u{2014}b\u{2014}c");
+        assert_eq!(normalize_dashes("a-b", DashStyle::Hyphen), "a\u{2010}b");
+    }
+
+    #[test]
+    fn borrowed() {
+        assert!(matches!(normalize_dashes("no dashes", DashStyle::HyphenMinus), Cow::Borrowed(_)));
+        assert!(matches!(normalize_dashes("well-known", DashStyle::HyphenMinus), Cow::Borrowed(_)));
+        assert!(matches!(normalize_dashes("well-known", DashStyle::EnDash), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn not_dashes() {
+        for text in ["a_b", "a~b", "a\u{2015}b", "a\u{FE58}b"] {
+            assert_eq!(normalize_dashes(text, DashStyle::HyphenMinus), text);
+        }
+    }
+}
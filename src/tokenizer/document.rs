@@ -0,0 +1,172 @@
+use std::ops::Range;
+use std::sync::LazyLock;
+
+use fancy_regex::Regex;
+
+use super::{EntityDecoding, Tokenizer, WebTokenizer};
+use crate::segmenter::{split_multi_spans, SegmentConfig};
+
+/// A named or numeric character reference, which the [WebTokenizer] decodes if it knows it.
+static ENTITY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"&(?:#[0-9]+|#[xX][0-9a-fA-F]+|[A-Za-z][A-Za-z0-9]*);?"#).unwrap());
+
+/// A token with its byte ranges in the sentence and in the whole document.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct DocumentToken {
+    pub text: String,
+    /// The byte range in the [Sentence::text].
+    pub sentence_range: Range<usize>,
+    /// The byte range in the text passed to [tokenize_document].
+    pub doc_range: Range<usize>,
+}
+
+impl AsRef<str> for DocumentToken {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+/// A sentence of a document, with its tokens.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Sentence {
+    /// The sentence as it is in the document, including single newlines.
+    pub text: String,
+    /// The byte range in the text passed to [tokenize_document].
+    pub range: Range<usize>,
+    pub tokens: Vec<DocumentToken>,
+}
+
+/// Split the `text` into sentences with [split_multi_spans], and each sentence into tokens with the
+/// [web_tokenizer](super::web_tokenizer), keeping track of the token ranges in both the sentence and the `text`.
+///
+/// ```rust
+/// use segtok::{segmenter::SegmentConfig, tokenizer::tokenize_document};
+///
+/// let text = "Hi there. Bye!";
+/// let sentences = tokenize_document(text, SegmentConfig::default());
+/// let bye = &sentences[1].tokens[0];
+/// assert_eq!((bye.sentence_range.clone(), bye.doc_range.clone()), (0..3, 10..13));
+/// assert_eq!(&text[bye.doc_range.clone()], "Bye");
+/// ```
+///
+/// Tokens that differ from the text, like un-escaped entities ("&amp;lt;") or rejoined hyphenated words
+/// ("catch-\nup"), get the range of the text they came from. Several tokens from the same text share its range.
+pub fn tokenize_document(text: &str, cfg: SegmentConfig) -> Vec<Sentence> {
+    split_multi_spans(text, cfg)
+        .into_iter()
+        .map(|range| {
+            let sentence = &text[range.clone()];
+            let tokens = align(sentence, WebTokenizer::default().tokenize(sentence))
+                .into_iter()
+                .map(|(token, span)| DocumentToken {
+                    text: token,
+                    doc_range: span.start + range.start..span.end + range.start,
+                    sentence_range: span,
+                })
+                .collect();
+            Sentence { text: sentence.to_owned(), range, tokens }
+        })
+        .collect()
+}
+
+/// Locate the tokens in the sentence, in order.
+///
+/// A token is found after the previous one and only whitespace, otherwise it is assumed to be changed
+/// by the tokenizer and takes the range up to the next token that is found.
+/// A token is only found at a word boundary, and never in a part of a decoded entity, like the "a" of "&amp;".
+fn align(sentence: &str, tokens: Vec<String>) -> Vec<(String, Range<usize>)> {
+    let mut res = Vec::with_capacity(tokens.len());
+    let mut pending = Vec::new();
+    let mut cursor = 0;
+
+    // the changed tokens share the range of the text between the tokens found around them
+    let trimmed = |gap: Range<usize>| {
+        let start = gap.end - sentence[gap.clone()].trim_start().len();
+        start..start + sentence[start..gap.end].trim_end().len()
+    };
+
+    let entities = ENTITY
+        .find_iter(sentence)
+        .map(Result::unwrap)
+        .filter(|entity| EntityDecoding::default().decode(entity.as_str()) != entity.as_str())
+        .map(|entity| entity.range())
+        .collect::<Vec<_>>();
+    let in_entity =
+        |span: Range<usize>| entities.iter().any(|entity| entity.start < span.end && span.start < entity.end);
+
+    for token in tokens {
+        let rest = &sentence[cursor..];
+        let is_mid_word = |pos: usize| {
+            token.starts_with(char::is_alphanumeric)
+                && rest[..pos].chars().next_back().is_some_and(char::is_alphanumeric)
+        };
+        let found = rest.match_indices(token.as_str()).map(|(pos, _)| pos).find(|&pos| {
+            (!pending.is_empty() && !is_mid_word(pos) || rest[..pos].trim().is_empty())
+                && !in_entity(cursor + pos..cursor + pos + token.len())
+        });
+
+        match found {
+            Some(pos) => {
+                let start = cursor + pos;
+                let gap = trimmed(cursor..start);
+                res.extend(pending.drain(..).map(|token| (token, gap.clone())));
+                cursor = start + token.len();
+                res.push((token, start..cursor));
+            }
+            None => pending.push(token),
+        }
+    }
+
+    let gap = trimmed(cursor..sentence.len());
+    res.extend(pending.into_iter().map(|token| (token, gap.clone())));
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges() {
+        let text = "  Hi there.\n\nIt is a\nsecond one.";
+        let sentences = tokenize_document(text, SegmentConfig::default());
+        assert_eq!(sentences.len(), 2);
+        assert_eq!(sentences[1].text, "It is a\nsecond one.");
+        assert_eq!(sentences[1].range, 13..32);
+
+        for sentence in sentences {
+            assert_eq!(&text[sentence.range.clone()], sentence.text);
+            for token in sentence.tokens {
+                assert_eq!(&sentence.text[token.sentence_range], token.text);
+                assert_eq!(&text[token.doc_range], token.text);
+            }
+        }
+    }
+
+    #[test]
+    fn changed_tokens() {
+        let text = "Go. P&lt;0.5 for catch-\nup growth &amp;&amp;";
+        let sentences = tokenize_document(text, SegmentConfig::default());
+        let tokens =
+            sentences[1].tokens.iter().map(|t| (t.text.as_str(), &text[t.doc_range.clone()])).collect::<Vec<_>>();
+        let expected = [
+            ("P", "P"),
+            ("<", "&lt;"),
+            ("0.5", "0.5"),
+            ("for", "for"),
+            ("catch-up", "catch-\nup"),
+            ("growth", "growth"),
+            ("&&", "&amp;&amp;"),
+        ];
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn entity_before_its_letters() {
+        let text = "Tom &amp; a cat.";
+        let sentences = tokenize_document(text, SegmentConfig::default());
+        let tokens =
+            sentences[0].tokens.iter().map(|t| (t.text.as_str(), &text[t.doc_range.clone()])).collect::<Vec<_>>();
+        assert_eq!(tokens, [("Tom", "Tom"), ("&", "&amp;"), ("a", "a"), ("cat", "cat"), (".", ".")]);
+    }
+}
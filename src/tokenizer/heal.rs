@@ -0,0 +1,108 @@
+use crate::segmenter::ABBREVIATIONS;
+
+/// Merge tokenized sentences that were split after an abbreviation, as a safety net after segmentation.
+///
+/// A sentence is merged with the next one if it ends with a known abbreviation ([ABBREVIATIONS])
+/// and its dot, and the next sentence starts with a lower-case word, like a continuation "and" or "of".
+/// The dot is joined back to the abbreviation, like the tokenizers do inside a sentence.
+///
+/// ```rust
+/// use segtok::tokenizer::heal_abbreviation_splits;
+///
+/// let sentences = vec![vec!["We", "met", "Dr", "."], vec!["and", "left", "."]];
+/// let sentences = sentences.into_iter().map(|s| s.into_iter().map(String::from).collect()).collect();
+/// assert_eq!(heal_abbreviation_splits(sentences), [["We", "met", "Dr.", "and", "left", "."]]);
+/// ```
+pub fn heal_abbreviation_splits(sentences: Vec<Vec<String>>) -> Vec<Vec<String>> {
+    let mut res: Vec<Vec<String>> = Vec::with_capacity(sentences.len());
+
+    for sentence in sentences {
+        match res.last_mut() {
+            Some(last) if starts_lower(&sentence) && ends_with_abbreviation(last) => {
+                if last.last().is_some_and(|token| token == ".") {
+                    last.pop();
+                    last.last_mut().into_iter().for_each(|token| token.push('.'));
+                }
+                last.extend(sentence);
+            }
+            _ => res.push(sentence),
+        }
+    }
+
+    res
+}
+
+/// Check if the tokens end with an abbreviation and its dot, either as one token "Dr." or two "Dr", ".".
+fn ends_with_abbreviation(tokens: &[String]) -> bool {
+    // the pattern looks at the words before the abbreviation, too, like "by" before an initial
+    let text = match tokens {
+        [words @ .., last] if last == "." => words.join(" "),
+        [.., last] if last.len() > 1 && last.ends_with('.') => {
+            let mut text = tokens.join(" ");
+            text.pop();
+            text
+        }
+        _ => return false,
+    };
+
+    !text.is_empty() && ABBREVIATIONS.is_match(&text).unwrap()
+}
+
+fn starts_lower(tokens: &[String]) -> bool {
+    tokens.first().is_some_and(|token| token.starts_with(char::is_lowercase))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::segmenter::split_single;
+    use crate::tokenizer::word_tokenizer;
+
+    fn sentences<const N: usize>(sentences: [&[&str]; N]) -> Vec<Vec<String>> {
+        sentences.iter().map(|tokens| tokens.iter().map(|&token| token.to_owned()).collect()).collect()
+    }
+
+    #[test]
+    fn abbreviations() {
+        let actual = heal_abbreviation_splits(sentences([
+            &["Compare", "Fig", "."],
+            &["and", "the", "table", "."],
+            &["It", "was", "approx."],
+            &["of", "size", "5", "."],
+        ]));
+        assert_eq!(
+            actual,
+            sentences([
+                &["Compare", "Fig.", "and", "the", "table", "."],
+                &["It", "was", "approx.", "of", "size", "5", "."]
+            ])
+        );
+    }
+
+    #[test]
+    fn initials() {
+        let actual = heal_abbreviation_splits(sentences([&["Written", "by", "J", "."], &["doe", "."]]));
+        assert_eq!(actual, sentences([&["Written", "by", "J.", "doe", "."]]));
+    }
+
+    #[test]
+    fn not_healed() {
+        let examples = [
+            sentences([&["It", "is", "done", "."], &["and", "then", "more", "."]]),
+            sentences([&["Compare", "Fig", "."], &["The", "table", "."]]),
+            sentences([&["Compare", "Fig"], &["and", "the", "table", "."]]),
+            sentences([&["."], &["and", "more", "."]]),
+            sentences([&[], &["and", "more", "."]]),
+        ];
+        for example in examples {
+            assert_eq!(heal_abbreviation_splits(example.clone()), example);
+        }
+    }
+
+    #[test]
+    fn tokenized() {
+        let sentences = split_single("Compare Fig.\nand the table.", Default::default());
+        let tokens = sentences.iter().map(|sentence| word_tokenizer(sentence)).collect();
+        assert_eq!(heal_abbreviation_splits(tokens), [word_tokenizer("Compare Fig. and the table.")]);
+    }
+}
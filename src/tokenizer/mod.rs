@@ -1,7 +1,17 @@
+mod amounts;
+mod apostrophes;
+mod camel_case;
 mod contractions;
+mod dashes;
+mod document;
+mod heal;
+mod pipeline;
 mod possessive_markers;
+mod punctuation;
 mod space_tokenizer;
 mod symbol_tokenizer;
+mod token;
+mod urls;
 mod web_tokenizer;
 mod word_tokenizer;
 
@@ -9,34 +19,42 @@ use std::sync::LazyLock;
 
 use fancy_regex::Regex;
 
+use crate::regex::char_class;
+use crate::segmenter::Script;
+
+pub use self::amounts::*;
+pub use self::apostrophes::*;
+pub use self::camel_case::*;
 pub use self::contractions::*;
+pub use self::dashes::*;
+pub use self::document::*;
+pub use self::heal::*;
+pub use self::pipeline::*;
 pub use self::possessive_markers::*;
+pub use self::punctuation::*;
 pub use self::space_tokenizer::*;
 pub use self::symbol_tokenizer::*;
+pub use self::token::*;
+pub use self::urls::*;
 pub use self::web_tokenizer::*;
 pub use self::word_tokenizer::*;
 
 #[deprecated]
 pub const LIST_OF_APOSTROPHES: &str = "'\u{00B4}\u{02B9}\u{02BC}\u{2019}\u{2032}";
 
-/// All apostrophe-like marks, including the ASCII "single quote".
-pub(crate) fn is_apostrophe(ch: char) -> bool {
-    matches!(ch, '\'' | '\u{00B4}' | '\u{02B9}' | '\u{02BC}' | '\u{2019}' | '\u{2032}')
-}
-
-/// Any apostrophe-like marks, including "prime" but not the ASCII "single quote".
+/// A regex class of the [APOSTROPHE_CHARS].
 pub const APOSTROPHES: &str = r#"['\u{00B4}\u{02B9}\u{02BC}\u{2019}\u{2032}]"#;
 
 #[deprecated]
-pub static APOSTROPHE_LIKE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r#"[\u{00B4}\u{02B9}\u{02BC}\u{2019}\u{2032}]"#).unwrap());
+pub static APOSTROPHE_LIKE: LazyLock<Regex> = LazyLock::new(|| Regex::new(&NON_QUOTE_APOSTROPHE).unwrap());
 
-/// Matcher for any apostrophe-like marks, including "prime" but not the ASCII "single quote".
-pub(crate) const NON_QUOTE_APOSTROPHE: &str = r#"[\u{00B4}\u{02B9}\u{02BC}\u{2019}\u{2032}]"#;
+/// A regex class of the [APOSTROPHE_CHARS], but not the ASCII "single quote".
+pub(crate) static NON_QUOTE_APOSTROPHE: LazyLock<String> =
+    LazyLock::new(|| char_class(APOSTROPHE_CHARS.iter().copied().filter(|&ch| is_non_quote_apostrophe(ch))));
 
 /// Matcher for any apostrophe-like marks, including "prime" but not the ASCII "single quote".
-pub(crate) const fn is_non_quote_apostrophe(ch: char) -> bool {
-    matches!(ch, '\u{00B4}' | '\u{02B9}' | '\u{02BC}' | '\u{2019}' | '\u{2032}')
+pub(crate) fn is_non_quote_apostrophe(ch: char) -> bool {
+    ch != '\'' && is_apostrophe(ch)
 }
 
 /// Any valid linebreak sequence (Windows, Unix, Mac, or U+2028).
@@ -69,3 +87,166 @@ pub const SPACE: &str = r#"[\p{Zs}\t]"#;
 pub static HYPHENATED_LINEBREAK: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(&format!(r#"({ALPHA_NUM}{HYPHEN}){SPACE}*?{LINEBREAK}{SPACE}*?({ALPHA_NUM})"#)).unwrap()
 });
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub struct TokenizeConfig {
+    /// Whether single underscores between alphanumerics join them into one word.
+    keep_underscores: bool,
+    /// Whether "--" tokens are replaced with an em-dash.
+    normalize_double_hyphen: bool,
+    /// Whether thin and hair spaces between digits join digit groups.
+    thin_space_in_numbers: bool,
+    /// How a middle dot between words is tokenized.
+    middot_policy: MiddotPolicy,
+    /// Which chars separate tokens.
+    spaces: SpaceConfig,
+    /// The script whose word separators also separate tokens, if any.
+    script: Option<Script>,
+    /// Whether primes after numbers are kept in the number tokens, as for feet and inches.
+    measurement_primes: bool,
+    /// Whether degree signs after numbers are kept in the number tokens.
+    attach_degrees: bool,
+    /// Whether semantic versions are kept as single tokens.
+    keep_semver: bool,
+    /// The length in bytes above which a URI or e-mail address is tokenized like any other text.
+    max_url_len: Option<usize>,
+    /// Which escape sequences the [web_tokenizer] decodes.
+    entity_decoding: EntityDecoding,
+    /// Whether LaTeX inline math is kept as single tokens.
+    inline_math: bool,
+    /// Where [split_contractions_with] splits the "n't" contractions.
+    contraction_style: ContractionStyle,
+    /// Whether the tokenizers and [split_contractions_with] reproduce the python package.
+    python_compat: bool,
+}
+
+/// How the [word_tokenizer] treats a middle dot "·" (U+00B7) right between two words.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum MiddotPolicy {
+    /// The middle dot is a token of its own: "V", "·", "m⁻¹".
+    #[default]
+    Separator,
+    /// The middle dot multiplies physical units and is kept inside of unit expressions: "V·m⁻¹", "kg·m·s⁻²".
+    /// Both sides must be unit-like: one to three letters, optionally with a superscript dimension.
+    UnitJoin,
+    /// The middle dot is kept between any letters, like the Catalan "punt volat" in "col·lecció",
+    /// as well as inside of unit expressions.
+    WordJoin,
+}
+
+impl TokenizeConfig {
+    /// Keep a single underscore surrounded by alphanumerics inside the word, as in "snake_case".
+    /// Leading, trailing, or repeated underscores are still split off ("__", "init", "__").
+    pub fn keep_underscores(mut self, value: bool) -> Self {
+        self.keep_underscores = value;
+        self
+    }
+
+    /// Replace typewriter-style "--" tokens with an em-dash "—", so "A--B" yields "A", "—", "B".
+    pub fn normalize_double_hyphen(mut self, value: bool) -> Self {
+        self.normalize_double_hyphen = value;
+        self
+    }
+
+    /// Treat a thin space (U+2009) or a hair space (U+200A) between digits as a digit group separator,
+    /// so the French "100\u{2009}000" is a single token. Elsewhere, they still split tokens.
+    pub fn thin_space_in_numbers(mut self, value: bool) -> Self {
+        self.thin_space_in_numbers = value;
+        self
+    }
+
+    /// How to treat a middle dot "·" right between two words, see [MiddotPolicy].
+    pub fn middot_policy(mut self, value: MiddotPolicy) -> Self {
+        self.middot_policy = value;
+        self
+    }
+
+    /// The whitespace that separates tokens, see [SpaceConfig::exclude].
+    /// Excluded spaces between digits join digit groups, like with [thin_space_in_numbers](Self::thin_space_in_numbers).
+    pub fn spaces(mut self, value: SpaceConfig) -> Self {
+        self.spaces = value;
+        self
+    }
+
+    /// When set, the [word separators](Script::word_separators) of the script separate tokens like spaces,
+    /// as the Ethiopic wordspace "፡" does.
+    pub fn script(mut self, script: Option<Script>) -> Self {
+        self.script = script;
+        self
+    }
+
+    /// Keep feet and inches with their numbers, so "5' 10\"" yields "5'" and "10\"", and "6'2\"" yields "6'" and "2\"".
+    /// The ASCII `'` and `"` only count as primes in such pairs, while "′" and "″" after a number always do.
+    pub fn measurement_primes(mut self, value: bool) -> Self {
+        self.measurement_primes = value;
+        self
+    }
+
+    /// Attach a degree sign, and a temperature unit after it, to the number before it,
+    /// so "37°C", "98.6°F", and the "90°" of "90° angle" are single tokens. Also attaches "℃" and "℉".
+    pub fn attach_degrees(mut self, value: bool) -> Self {
+        self.attach_degrees = value;
+        self
+    }
+
+    /// Keep a semantic version as a single token, with its pre-release and build metadata,
+    /// so "1.0.0-rc.1+build.5" is not split at the "+". See [SEMVER] for the grammar.
+    pub fn keep_semver(mut self, value: bool) -> Self {
+        self.keep_semver = value;
+        self
+    }
+
+    /// When set, the [web_tokenizer] only keeps URIs and e-mail addresses up to this length in bytes as single tokens,
+    /// and splits the longer ones like any other text, so a runaway match on malformed input does not swallow it.
+    pub fn max_url_len(mut self, value: Option<usize>) -> Self {
+        self.max_url_len = value;
+        self
+    }
+
+    /// Which escape sequences the [web_tokenizer] decodes outside of URIs and e-mail addresses,
+    /// see [EntityDecoding]. By default, all HTML entities are.
+    pub fn entity_decoding(mut self, value: EntityDecoding) -> Self {
+        self.entity_decoding = value;
+        self
+    }
+
+    /// Keep LaTeX inline math, like "$x = 3.14$" or "\\(a + b\\)", as a single token, spaces included.
+    /// See [INLINE_MATH](crate::segmenter::INLINE_MATH) for the delimiters.
+    pub fn inline_math(mut self, value: bool) -> Self {
+        self.inline_math = value;
+        self
+    }
+
+    /// Where [split_contractions_with] splits the negative "n't" contractions, like "don't",
+    /// see [ContractionStyle]. The other contractions are split the same way by both styles.
+    pub fn contraction_style(mut self, value: ContractionStyle) -> Self {
+        self.contraction_style = value;
+        self
+    }
+
+    /// Reproduce the output of the [python package](https://github.com/fnl/segtok) (version 1.5.11),
+    /// including its bugs, for a byte-for-byte parity with the tools built on it:
+    ///
+    /// - The ASCII single quote after a plural acronym is not kept before trailing punctuation,
+    ///   so "CPUs'." yields "CPUs", "'", and "." instead of "CPUs'" and ".".
+    /// - [split_contractions_with] splits a token at each of its apostrophes, as in the
+    ///   [contractions bug](https://github.com/fnl/segtok/issues/26): "OʼHaraʼs" yields "OʼHara", "O", and "ʼHaraʼs".
+    ///   Chained contractions, like "shouldn't've", are not split, and a lone "n't" yields an empty token before it.
+    /// - A leading dot of a decimal number is split off, so ".5" yields "." and "5".
+    ///
+    /// The parity is of the tokenizers only. The [SegmentConfig](crate::segmenter::SegmentConfig) default keeps
+    /// the sentence start casing of the python package, as long as the
+    /// [require_capital_start](crate::segmenter::SegmentConfig::require_capital_start) and
+    /// [allow_camelcase_start](crate::segmenter::SegmentConfig::allow_camelcase_start) options stay enabled,
+    /// but not all of its sentences: a run of terminals, like "?! ?!", ends a single sentence,
+    /// and the lines of [split_single](crate::segmenter::split_single) are trimmed, with no blank ones.
+    pub fn python_compat(mut self, value: bool) -> Self {
+        self.python_compat = value;
+        self
+    }
+
+    /// Check if the char separates tokens.
+    fn is_space(self, ch: char) -> bool {
+        self.spaces.is_space(ch) || self.script.is_some_and(|script| script.word_separators().contains(&ch))
+    }
+}
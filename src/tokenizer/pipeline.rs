@@ -0,0 +1,193 @@
+use super::{space_tokenizer, symbol_tokenizer, web_tokenizer_with, word_tokenizer_with, TokenizeConfig};
+use crate::segmenter::{split_multi, SegmentConfig};
+
+/// A tokenizer that splits a sentence into tokens, so the tokenizers can be swapped in a pipeline.
+///
+/// Closures, like `|s: &str| split_contractions(web_tokenizer(s))`, implement it, too.
+pub trait Tokenizer {
+    fn tokenize(&self, sentence: &str) -> Vec<String>;
+}
+
+impl<F: Fn(&str) -> Vec<String>> Tokenizer for F {
+    fn tokenize(&self, sentence: &str) -> Vec<String> {
+        self(sentence)
+    }
+}
+
+/// The [space_tokenizer] as a [Tokenizer].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct SpaceTokenizer;
+
+impl Tokenizer for SpaceTokenizer {
+    fn tokenize(&self, sentence: &str) -> Vec<String> {
+        space_tokenizer(sentence).map(ToOwned::to_owned).collect()
+    }
+}
+
+/// The [symbol_tokenizer] as a [Tokenizer].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct SymbolTokenizer;
+
+impl Tokenizer for SymbolTokenizer {
+    fn tokenize(&self, sentence: &str) -> Vec<String> {
+        symbol_tokenizer(sentence).map(ToOwned::to_owned).collect()
+    }
+}
+
+/// The [word_tokenizer](super::word_tokenizer) as a [Tokenizer], with the options of a [TokenizeConfig].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct WordTokenizer(pub TokenizeConfig);
+
+impl Tokenizer for WordTokenizer {
+    fn tokenize(&self, sentence: &str) -> Vec<String> {
+        word_tokenizer_with(sentence, self.0)
+    }
+}
+
+/// The [web_tokenizer](super::web_tokenizer) as a [Tokenizer], with the options of a [TokenizeConfig].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct WebTokenizer(pub TokenizeConfig);
+
+impl Tokenizer for WebTokenizer {
+    fn tokenize(&self, sentence: &str) -> Vec<String> {
+        web_tokenizer_with(sentence, self.0)
+    }
+}
+
+/// Split the `text` into sentences with [split_multi], and each sentence into tokens with the `tokenizer`.
+///
+/// ```rust
+/// use segtok::{segmenter::SegmentConfig, tokenizer::*};
+///
+/// let sentences = tokenize_sentences("Hi there. Bye!", SegmentConfig::default(), &WordTokenizer::default());
+/// assert_eq!(sentences, [vec!["Hi", "there", "."], vec!["Bye", "!"]]);
+/// ```
+pub fn tokenize_sentences(text: &str, cfg: SegmentConfig, tokenizer: &dyn Tokenizer) -> Vec<Vec<String>> {
+    split_multi(text, cfg).iter().map(|sentence| tokenizer.tokenize(sentence)).collect()
+}
+
+/// Split the `text` into sentences and tokens with the [web_tokenizer](super::web_tokenizer),
+/// and flatten the tokens of all sentences into one list.
+/// The mask of the same length is `true` for the first token of each sentence.
+///
+/// ```rust
+/// use segtok::{segmenter::SegmentConfig, tokenizer::sentence_start_mask};
+///
+/// let (tokens, mask) = sentence_start_mask("Hi there. Bye!", SegmentConfig::default());
+/// assert_eq!(tokens, ["Hi", "there", ".", "Bye", "!"]);
+/// assert_eq!(mask, [true, false, false, true, false]);
+/// ```
+pub fn sentence_start_mask(text: &str, cfg: SegmentConfig) -> (Vec<String>, Vec<bool>) {
+    tokenize_sentences(text, cfg, &WebTokenizer::default())
+        .into_iter()
+        .flat_map(|tokens| tokens.into_iter().enumerate().map(|(idx, token)| (token, idx == 0)))
+        .unzip()
+}
+
+/// Split the `text` into sentences and tokens with the [web_tokenizer](super::web_tokenizer),
+/// and flatten the tokens of all sentences into one list, each paired with the index of its sentence.
+///
+/// ```rust
+/// use segtok::{segmenter::SegmentConfig, tokenizer::tokenize_flat};
+///
+/// let tokens = tokenize_flat("Hi there. Bye!", SegmentConfig::default());
+/// assert_eq!(tokens, [(0, "Hi"), (0, "there"), (0, "."), (1, "Bye"), (1, "!")].map(|(i, t)| (i, t.to_string())));
+/// ```
+pub fn tokenize_flat(text: &str, cfg: SegmentConfig) -> Vec<(usize, String)> {
+    tokenize_sentences(text, cfg, &WebTokenizer::default())
+        .into_iter()
+        .enumerate()
+        .flat_map(|(idx, tokens)| tokens.into_iter().map(move |token| (idx, token)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::{split_contractions, web_tokenizer};
+
+    const TEXT: &str = "Don't visit http://x.com/a_b today. It's done.";
+
+    fn tokenize(tokenizer: &dyn Tokenizer) -> Vec<Vec<String>> {
+        tokenize_sentences(TEXT, SegmentConfig::default(), tokenizer)
+    }
+
+    #[test]
+    fn space() {
+        assert_eq!(
+            tokenize(&SpaceTokenizer),
+            [vec!["Don't", "visit", "http://x.com/a_b", "today."], vec!["It's", "done."]]
+        );
+    }
+
+    #[test]
+    fn symbol() {
+        let expected = [
+            vec!["Don", "'", "t", "visit", "http", "://", "x", ".", "com", "/", "a", "_", "b", "today", "."],
+            vec!["It", "'", "s", "done", "."],
+        ];
+        assert_eq!(tokenize(&SymbolTokenizer), expected);
+    }
+
+    #[test]
+    fn word() {
+        let expected = [
+            vec!["Don't", "visit", "http", "://", "x.com", "/", "a", "_", "b", "today", "."],
+            vec!["It's", "done", "."],
+        ];
+        assert_eq!(tokenize(&WordTokenizer::default()), expected);
+
+        let tokenizer = WordTokenizer(TokenizeConfig::default().keep_underscores(true));
+        assert_eq!(tokenize(&tokenizer)[0][6], "a_b");
+    }
+
+    #[test]
+    fn web() {
+        let expected = [vec!["Don't", "visit", "http://x.com/a_b", "today", "."], vec!["It's", "done", "."]];
+        assert_eq!(tokenize(&WebTokenizer::default()), expected);
+    }
+
+    #[test]
+    fn start_mask() {
+        let (tokens, mask) = sentence_start_mask(TEXT, SegmentConfig::default());
+        assert_eq!(tokens, ["Don't", "visit", "http://x.com/a_b", "today", ".", "It's", "done", "."]);
+        assert_eq!(mask, [true, false, false, false, false, true, false, false]);
+
+        assert_eq!(sentence_start_mask("", SegmentConfig::default()), (vec![], vec![]));
+    }
+
+    #[test]
+    fn flat() {
+        let tokens = tokenize_flat(TEXT, SegmentConfig::default());
+        let ids = tokens.iter().map(|&(idx, _)| idx).collect::<Vec<_>>();
+        assert_eq!(ids, [0, 0, 0, 0, 0, 1, 1, 1]);
+        assert_eq!(tokens[5], (1, "It's".to_string()));
+
+        let text = include_str!("../../tests/test_business.txt");
+        let tokens = tokenize_flat(text, SegmentConfig::default());
+        assert!(tokens.windows(2).all(|pair| pair[1].0 - pair[0].0 <= 1));
+        assert_eq!(tokens.last().map(|&(idx, _)| idx + 1), Some(split_multi(text, SegmentConfig::default()).len()));
+
+        assert_eq!(tokenize_flat("", SegmentConfig::default()), []);
+    }
+
+    #[test]
+    fn numero() {
+        let actual =
+            tokenize_sentences("See No. 1. It is item No. 1 here.", Default::default(), &WebTokenizer::default());
+        assert_eq!(actual, [vec!["See", "No.", "1", "."], vec!["It", "is", "item", "No.", "1", "here", "."]]);
+    }
+
+    #[test]
+    fn citations() {
+        let actual =
+            tokenize_sentences("Shown in [1]. Next claim [2,3].", Default::default(), &WebTokenizer::default());
+        assert_eq!(actual, [vec!["Shown", "in", "[", "1", "]", "."], vec!["Next", "claim", "[", "2,3", "]", "."]]);
+    }
+
+    #[test]
+    fn closure() {
+        let expected = [vec!["Do", "n't", "visit", "http://x.com/a_b", "today", "."], vec!["It", "'s", "done", "."]];
+        assert_eq!(tokenize(&|s: &str| split_contractions(web_tokenizer(s))), expected);
+    }
+}
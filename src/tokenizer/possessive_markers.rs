@@ -29,11 +29,11 @@ pub fn split_possessive_markers(mut tokens: Vec<String>) -> Vec<String> {
 
         if IS_POSSESSIVE.is_match(token).unwrap() {
             if let Some(((_2idx, _2ch), (_1idx, _1ch))) = token.char_indices().tuple_windows::<(_, _)>().last() {
-                if _1ch.to_ascii_lowercase() == 's' && is_apostrophe(_2ch) {
+                if _1ch.eq_ignore_ascii_case(&'s') && is_apostrophe(_2ch) {
                     let suffix = token.split_off(_2idx);
                     idx += 1;
                     tokens.insert(idx, suffix);
-                } else if _2ch.to_ascii_lowercase() == 's' && is_apostrophe(_1ch) {
+                } else if _2ch.eq_ignore_ascii_case(&'s') && is_apostrophe(_1ch) {
                     let suffix = token.split_off(_1idx);
                     idx += 1;
                     tokens.insert(idx, suffix);
@@ -86,6 +86,17 @@ mod tests {
         assert_eq!(res, ["CHARLES", "'"]);
     }
 
+    #[test]
+    fn split_plural_acronyms() {
+        use crate::tokenizer::word_tokenizer;
+
+        let res = split_possessive_markers(word_tokenizer("The URLs' formats differ from the CPUs'."));
+        assert_eq!(res, ["The", "URLs", "'", "formats", "differ", "from", "the", "CPUs", "'", "."]);
+
+        let res = split_possessive_markers(word_tokenizer("Ask the PhDs'!"));
+        assert_eq!(res, ["Ask", "the", "PhDs", "'", "!"]);
+    }
+
     #[test]
     fn split_unicode() {
         assert!(is_apostrophe('\u{2032}'));
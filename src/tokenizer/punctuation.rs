@@ -0,0 +1,73 @@
+use itertools::Itertools;
+
+use crate::segmenter::is_sentence_terminal;
+
+/// A function to collapse runs of repeated punctuation in tokens, as in "Really???" or "No!!!".
+///
+/// Takes the output of any of the tokenizer functions and produces an updated list:
+///
+/// ```rust
+/// use segtok::tokenizer::normalize_repeated_punct;
+///
+/// let tokens = ["No", "!!!", "Really", "?!?!", "Wait", "...."].map(String::from).to_vec();
+/// assert_eq!(normalize_repeated_punct(tokens), ["No", "!", "Really", "?!", "Wait", "..."]);
+/// ```
+///
+/// Only the tokens of sentence terminals and the commas, colons, and semicolons are changed,
+/// so tokens like "--" or ":)" are kept:
+///
+/// 1. Each run of the same char is collapsed to one char ("!!!" to "!", ",," to ","),
+///    except a run of two or more dots, which is an ellipsis of exactly three dots ("...." to "...").
+/// 2. A token of only question and exclamation marks is reduced to the distinct ones,
+///    in the order they first appear ("?!?!" to "?!", "!!??" to "!?").
+pub fn normalize_repeated_punct(tokens: Vec<String>) -> Vec<String> {
+    tokens.into_iter().map(|token| if is_collapsible(&token) { collapse(&token) } else { token }).collect()
+}
+
+fn is_collapsible(token: &str) -> bool {
+    token.chars().all(|ch| is_sentence_terminal(ch) || matches!(ch, ',' | ';' | ':'))
+}
+
+fn collapse(token: &str) -> String {
+    if token.chars().all(|ch| matches!(ch, '?' | '!')) {
+        return token.chars().unique().collect();
+    }
+
+    let mut res = String::with_capacity(token.len());
+    for (ch, run) in &token.chars().chunk_by(|&ch| ch) {
+        match ch {
+            '.' if run.count() > 1 => res.push_str("..."),
+            ch => res.push(ch),
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize<const N: usize>(tokens: [&str; N]) -> Vec<String> {
+        normalize_repeated_punct(tokens.map(String::from).to_vec())
+    }
+
+    #[test]
+    fn runs() {
+        assert_eq!(
+            normalize(["!!!", "???", "....", "..", "...", ".", ",,", "。。"]),
+            ["!", "?", "...", "...", "...", ".", ",", "。"]
+        );
+    }
+
+    #[test]
+    fn mixed() {
+        assert_eq!(normalize(["?!?!", "!!??", "?!", "!?!"]), ["?!", "!?", "?!", "!?"]);
+        assert_eq!(normalize(["!!!....", "?..", ".!."]), ["!...", "?...", ".!."]);
+    }
+
+    #[test]
+    fn unchanged() {
+        let tokens = ["--", ":)", "a!!", "...!?", "e.g.", "U.S.", "…", "", "**"];
+        assert_eq!(normalize(tokens), tokens);
+    }
+}
@@ -14,6 +14,50 @@ pub fn space_tokenizer(sentence: &str) -> impl Iterator<Item = &str> {
     sentence.split_whitespace()
 }
 
+/// The Unicode space separators (Zs), which a [SpaceConfig] may exclude from the whitespace.
+const SPACE_SEPARATORS: [char; 17] = [
+    ' ', '\u{00A0}', '\u{1680}', '\u{2000}', '\u{2001}', '\u{2002}', '\u{2003}', '\u{2004}', '\u{2005}', '\u{2006}',
+    '\u{2007}', '\u{2008}', '\u{2009}', '\u{200A}', '\u{202F}', '\u{205F}', '\u{3000}',
+];
+
+/// The whitespace definition of the tokenizers: any Unicode whitespace, except the excluded spaces.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub struct SpaceConfig {
+    /// A bit per excluded char of the [SPACE_SEPARATORS].
+    excluded: u32,
+}
+
+impl SpaceConfig {
+    /// Space chars that do not separate tokens, like the no-break space (U+00A0) or the figure space (U+2007),
+    /// so "1\u{2007}000" stays a single token.
+    ///
+    /// Only space separators (Zs) can be excluded, other chars are ignored;
+    /// tabs and linebreaks always separate tokens.
+    pub fn exclude(mut self, spaces: impl IntoIterator<Item = char>) -> Self {
+        for space in spaces {
+            if let Some(idx) = SPACE_SEPARATORS.iter().position(|&ch| ch == space) {
+                self.excluded |= 1 << idx;
+            }
+        }
+        self
+    }
+
+    /// Check if the char separates tokens.
+    pub fn is_space(self, ch: char) -> bool {
+        ch.is_whitespace()
+            && (self.excluded == 0
+                || SPACE_SEPARATORS
+                    .iter()
+                    .position(|&space| space == ch)
+                    .is_none_or(|idx| self.excluded & 1 << idx == 0))
+    }
+}
+
+/// The [space_tokenizer] with the whitespace definition of a [SpaceConfig].
+pub fn space_tokenizer_with(sentence: &str, cfg: SpaceConfig) -> impl Iterator<Item = &str> {
+    sentence.split(move |ch| cfg.is_space(ch)).filter(|s| !s.is_empty())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -31,4 +75,15 @@ mod tests {
         let expected = ["1", "2", "3"];
         assert_eq!(space_tokenizer(sentence).collect::<Vec<_>>(), expected);
     }
+
+    #[test]
+    fn excluded_spaces() {
+        let cfg = SpaceConfig::default().exclude(['\u{00A0}', '\u{2007}', 'x', '\t']);
+        let sentence = "1\u{2007}000 km\u{00A0}h\u{2009}and\tmore";
+        let expected = ["1\u{2007}000", "km\u{00A0}h", "and", "more"];
+        assert_eq!(space_tokenizer_with(sentence, cfg).collect::<Vec<_>>(), expected);
+
+        let expected = ["1", "000", "km", "h", "and", "more"];
+        assert_eq!(space_tokenizer_with(sentence, Default::default()).collect::<Vec<_>>(), expected);
+    }
 }
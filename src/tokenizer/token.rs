@@ -0,0 +1,43 @@
+use std::ops::Range;
+
+/// A token together with its byte range in the original text.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Token {
+    pub text: String,
+    pub span: Range<usize>,
+}
+
+impl Token {
+    pub fn new(text: impl Into<String>, span: Range<usize>) -> Self {
+        Self { text: text.into(), span }
+    }
+}
+
+impl AsRef<str> for Token {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl From<Token> for String {
+    fn from(value: Token) -> Self {
+        value.text
+    }
+}
+
+/// A token of the [word_tokenizer_trivia](super::word_tokenizer_trivia) with the text around it,
+/// so the concatenation of all `leading_ws`, `text`, and `trailing_punct` reproduces the sentence.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct TriviaToken {
+    /// The whitespace between the token and the one before it.
+    pub leading_ws: String,
+    pub text: String,
+    /// The punctuation tokens attached to the end of the token, like "." or ");".
+    pub trailing_punct: String,
+}
+
+impl AsRef<str> for TriviaToken {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
@@ -1,10 +1,11 @@
+use std::borrow::Cow;
 use std::sync::LazyLock;
 
 use either::Either;
-use fancy_regex::Regex;
+use fancy_regex::{Captures, Regex};
 
 use crate::regex::RegexSplitExt;
-use crate::tokenizer::word_tokenizer;
+use crate::tokenizer::{is_non_quote_apostrophe, word_tokenizer_with, TokenizeConfig};
 
 pub static URI_OR_MAIL: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(
@@ -33,18 +34,125 @@ pub static URI_OR_MAIL: LazyLock<Regex> = LazyLock::new(|| {
     .unwrap()
 });
 
-/// The web tokenizer works like the [word_tokenizer], but does not split URIs or
-/// e-mail addresses. It also un-escapes all escape sequences (except in URIs or email addresses).
+/// The kind of a token of the [web_tokenizer_typed].
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum WebTokenKind {
+    /// A URI matched by the [URI_OR_MAIL] pattern, like "http://here.to/me".
+    Url,
+    /// An e-mail address matched by the [URI_OR_MAIL] pattern.
+    Email,
+    /// Any other token of the [word_tokenizer](crate::tokenizer::word_tokenizer).
+    Other,
+}
+
+/// Which escape sequences the [web_tokenizer_with] decodes, see [TokenizeConfig::entity_decoding].
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum EntityDecoding {
+    /// Keep the escape sequences as they are in the text.
+    None,
+    /// Decode the five entities of XML ("&lt;", "&gt;", "&amp;", "&quot;", and "&apos;") and the numeric
+    /// character references, like "&#60;" or "&#x3C;", but keep the HTML ones, like "&hellip;", as they are.
+    XmlOnly,
+    /// Decode all HTML entities, with [htmlize::unescape].
+    #[default]
+    Full,
+}
+
+impl EntityDecoding {
+    /// Decode the escape sequences of the text.
+    pub(crate) fn decode(self, text: &str) -> Cow<'_, str> {
+        match self {
+            EntityDecoding::None => Cow::Borrowed(text),
+            EntityDecoding::XmlOnly if !text.contains('&') => Cow::Borrowed(text),
+            EntityDecoding::XmlOnly => XML_ENTITY.replace_all(text, |captures: &Captures| {
+                xml_entity(captures).map_or_else(|| captures[0].to_owned(), String::from)
+            }),
+            EntityDecoding::Full => htmlize::unescape(text),
+        }
+    }
+}
+
+/// The predefined entities of XML, and the decimal and hexadecimal character references.
+static XML_ENTITY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"&(?:(lt|gt|amp|quot|apos)|#([0-9]{1,7})|#[xX]([0-9a-fA-F]{1,6}));"#).unwrap());
+
+/// The char of an [XML_ENTITY] match, if it refers to a valid one.
+fn xml_entity(captures: &Captures) -> Option<char> {
+    match (captures.get(1), captures.get(2), captures.get(3)) {
+        (Some(name), _, _) => Some(match name.as_str() {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            _ => '\'',
+        }),
+        (_, Some(dec), _) => dec.as_str().parse().ok().and_then(char::from_u32),
+        (_, _, Some(hex)) => u32::from_str_radix(hex.as_str(), 16).ok().and_then(char::from_u32),
+        _ => None,
+    }
+}
+
+/// The web tokenizer works like the [word_tokenizer](crate::tokenizer::word_tokenizer), but does not split URIs or
+/// e-mail addresses. It also un-escapes all escape sequences (except in URIs or email addresses),
+/// unless the [entity_decoding](TokenizeConfig::entity_decoding) says otherwise.
 pub fn web_tokenizer(sentence: &str) -> Vec<String> {
+    web_tokenizer_with(sentence, Default::default())
+}
+
+/// The [web_tokenizer] with the options of a [TokenizeConfig].
+pub fn web_tokenizer_with(sentence: &str, cfg: TokenizeConfig) -> Vec<String> {
+    web_tokenizer_typed_with(sentence, cfg).into_iter().map(|(token, _)| token).collect()
+}
+
+/// The [web_tokenizer], but all apostrophe-like marks in the tokens, like "’" (U+2019) or "ʼ" (U+02BC),
+/// are replaced with the ASCII "'", so the tokens compare equal no matter the apostrophes of the text.
+/// URIs and e-mail addresses are kept as they are.
+///
+/// ```rust
+/// use segtok::tokenizer::{split_contractions, web_tokenizer_normalized_apostrophes};
+///
+/// let tokens = split_contractions(web_tokenizer_normalized_apostrophes("I don’t know OʼHara’s plan."));
+/// assert_eq!(tokens, ["I", "do", "n't", "know", "O'Hara", "'s", "plan", "."]);
+/// ```
+pub fn web_tokenizer_normalized_apostrophes(sentence: &str) -> Vec<String> {
+    web_tokenizer_typed(sentence)
+        .into_iter()
+        .map(|(token, kind)| match kind {
+            WebTokenKind::Other if token.contains(is_non_quote_apostrophe) => {
+                token.replace(is_non_quote_apostrophe, "'")
+            }
+            _ => token,
+        })
+        .collect()
+}
+
+/// The same as the [web_tokenizer], but tells URIs and e-mail addresses apart from the other tokens:
+///
+/// ```rust
+/// use segtok::tokenizer::{web_tokenizer_typed, WebTokenKind};
+///
+/// let tokens = web_tokenizer_typed("Mail me@mo.re");
+/// assert_eq!(tokens[1], ("me@mo.re".to_owned(), WebTokenKind::Email));
+/// ```
+pub fn web_tokenizer_typed(sentence: &str) -> Vec<(String, WebTokenKind)> {
+    web_tokenizer_typed_with(sentence, Default::default())
+}
+
+/// The [web_tokenizer_typed] with the options of a [TokenizeConfig].
+pub fn web_tokenizer_typed_with(sentence: &str, cfg: TokenizeConfig) -> Vec<(String, WebTokenKind)> {
     URI_OR_MAIL
         .split_with_separators(sentence)
         .enumerate()
         .flat_map(|(i, span)| {
-            if i % 2 == 0 {
-                let span = &htmlize::unescape(span);
-                Either::Left(word_tokenizer(span).into_iter())
+            let is_too_long = cfg.max_url_len.is_some_and(|max| span.len() > max);
+            if i % 2 == 0 || is_too_long {
+                let span = &cfg.entity_decoding.decode(span);
+                let tokens = word_tokenizer_with(span, cfg).into_iter();
+                Either::Left(tokens.map(|token| (token, WebTokenKind::Other)))
             } else {
-                Either::Right(std::iter::once(span.to_owned()))
+                // a URI may contain an "@" for the user, but an e-mail address never contains a scheme
+                let kind = if span.contains("://") { WebTokenKind::Url } else { WebTokenKind::Email };
+                Either::Right(std::iter::once((span.to_owned(), kind)))
             }
         })
         .collect()
@@ -123,4 +231,76 @@ mod tests {
         .collect::<Vec<_>>();
         assert_eq!(web_tokenizer(input), expected);
     }
+
+    #[test]
+    fn multiline() {
+        let cfgs = [
+            TokenizeConfig::default(),
+            TokenizeConfig::default().thin_space_in_numbers(true),
+            TokenizeConfig::default().keep_semver(true).measurement_primes(true),
+        ];
+        for cfg in cfgs {
+            for input in
+                ["word1\nword2", "word1\r\nword2", "\nword1 \n\t word2\n", "word1\n\nword2", "word1\u{2028}word2"]
+            {
+                assert_eq!(web_tokenizer_with(input, cfg), ["word1", "word2"], "{input:?}");
+            }
+            assert_eq!(web_tokenizer_with("the end.\n", cfg), ["the", "end", "."]);
+            assert_eq!(web_tokenizer_with("catch-\nup at 10\n000", cfg), ["catch-up", "at", "10", "000"]);
+        }
+    }
+
+    #[test]
+    fn typed() {
+        let input = "Ask ftp://me@here.to/x or me@here.to (now).";
+        let expected = [
+            ("Ask", WebTokenKind::Other),
+            ("ftp://me@here.to/x", WebTokenKind::Url),
+            ("or", WebTokenKind::Other),
+            ("me@here.to", WebTokenKind::Email),
+            ("(", WebTokenKind::Other),
+            ("now", WebTokenKind::Other),
+            (")", WebTokenKind::Other),
+            (".", WebTokenKind::Other),
+        ]
+        .map(|(token, kind)| (token.to_owned(), kind));
+        assert_eq!(web_tokenizer_typed(input), expected);
+    }
+
+    #[test]
+    fn normalized_apostrophes() {
+        let input = "We’ll see O\u{02BC}Neil\u{02BC}s car, it\u{00B4}s 5\u{2032} at http://x.org/it’s now.";
+        let expected = ["We'll", "see", "O'Neil's", "car", ",", "it's", "5'", "at", "http://x.org/it’s", "now", "."];
+        assert_eq!(web_tokenizer_normalized_apostrophes(input), expected);
+        assert_eq!(web_tokenizer_normalized_apostrophes("Don't stop."), web_tokenizer("Don't stop."));
+    }
+
+    #[test]
+    fn entity_decoding() {
+        let decode = |input, value| web_tokenizer_with(input, TokenizeConfig::default().entity_decoding(value));
+        let input = "&lt;tag&gt; &hellip;";
+        assert_eq!(decode(input, EntityDecoding::None), ["&", "lt", ";", "tag", "&", "gt", ";", "&", "hellip", ";"]);
+        assert_eq!(decode(input, EntityDecoding::XmlOnly), ["<", "tag", ">", "&", "hellip", ";"]);
+        assert_eq!(decode(input, EntityDecoding::Full), ["<", "tag", ">", "…"]);
+        assert_eq!(web_tokenizer(input), decode(input, EntityDecoding::Full));
+
+        let input = "A &amp;lt; B &#38; C &#x3C; D &#xD800; E &apos;x&quot;";
+        let expected = ["A", "&", "lt", ";", "B", "&", "C", "<", "D", "&#", "xD800", ";", "E", "'", "x", "\""];
+        assert_eq!(decode(input, EntityDecoding::XmlOnly), expected);
+    }
+
+    #[test]
+    fn max_url_len() {
+        let url = format!("http://here.to/{}", "a-b_c/".repeat(100));
+        let input = format!("See {url} now");
+        let cfg = TokenizeConfig::default().max_url_len(Some(100));
+
+        assert_eq!(web_tokenizer(&input), ["See", url.as_str(), "now"]);
+        let tokens = web_tokenizer_typed_with(&input, cfg);
+        assert!(tokens.len() > 100);
+        assert!(tokens.iter().all(|(token, kind)| token.len() <= 100 && *kind == WebTokenKind::Other));
+
+        let input = "Mail me@mo.re or http://here.to/me";
+        assert_eq!(web_tokenizer_with(input, cfg), web_tokenizer(input));
+    }
 }
@@ -1,15 +1,68 @@
+use std::borrow::Cow;
+use std::ops::Range;
 use std::sync::LazyLock;
 
+use either::Either;
 use fancy_regex::{Captures, Regex};
 
 use super::{
-    is_non_quote_apostrophe, space_tokenizer, ALPHA_NUM, HYPHEN, HYPHENATED_LINEBREAK, LETTER, NON_QUOTE_APOSTROPHE,
-    NUMBER,
+    is_non_quote_apostrophe, space_tokenizer, MiddotPolicy, SpaceConfig, TokenizeConfig, TriviaToken, ALPHA_NUM,
+    HYPHEN, HYPHENATED_LINEBREAK, LETTER, NON_QUOTE_APOSTROPHE, NUMBER,
 };
 use crate::regex::{Partition, PartitionIter};
-use crate::segmenter::is_sentence_terminal;
+use crate::segmenter::{is_sentence_terminal, INLINE_MATH, SENTENCE_TERMINALS};
 
-pub static WORD_BITS: LazyLock<Regex> = LazyLock::new(|| {
+pub static WORD_BITS: LazyLock<Regex> = LazyLock::new(|| word_bits_regex(false, false));
+
+/// The same as [WORD_BITS], but single underscores surrounded by alphanumerics are part of the word.
+pub static WORD_BITS_WITH_UNDERSCORES: LazyLock<Regex> = LazyLock::new(|| word_bits_regex(true, false));
+
+/// The word bits of the [TokenizeConfig::python_compat] mode, with and without underscores.
+static PYTHON_WORD_BITS: LazyLock<[Regex; 2]> =
+    LazyLock::new(|| [word_bits_regex(false, true), word_bits_regex(true, true)]);
+
+/// Numbers with primes, like feet and inches, which the [TokenizeConfig::measurement_primes] keep as tokens.
+pub static MEASUREMENT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?ux)
+            (?<! [\w.,] )
+            (?: (\d+ (?:[.,]\d+)? ['′]) \s* (\d+ (?:[.,]\d+)? ["″])   # feet and inches, like 5' 10"
+            |   (\d+ (?:[.,]\d+)? [′″])                               # a number with a prime, like 5′
+            )
+            (?! \w )
+        "#,
+    )
+    .unwrap()
+});
+
+/// Semantic versions (semver.org), with an optional "v" prefix, pre-release and build metadata,
+/// like "v2.3.4" or "1.0.0-rc.1+build.5", which the [TokenizeConfig::keep_semver] keep as tokens.
+pub static SEMVER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?x)
+            (?<! [\w.+-] )
+            v? (?:0|[1-9]\d*) \. (?:0|[1-9]\d*) \. (?:0|[1-9]\d*)   # major.minor.patch
+            (?: - [0-9A-Za-z-]+ (?: \. [0-9A-Za-z-]+ )* )?          # pre-release, like -rc.1
+            (?: \+ [0-9A-Za-z-]+ (?: \. [0-9A-Za-z-]+ )* )?         # build metadata, like +build.5
+            (?! [\w+-] | \.[0-9A-Za-z] )
+        "#,
+    )
+    .unwrap()
+});
+
+fn word_bits_regex(underscores: bool, python_compat: bool) -> Regex {
+    let underscore = if underscores { format!("| _ (?={ALPHA_NUM})") } else { String::new() };
+    let (acronym_possessive, leading_dot) = if python_compat {
+        (String::new(), "")
+    } else {
+        let acronym_possessive = format!(
+            r#"| # ...or before trailing punctuation, if the s pluralizes an acronym ("CPUs'.")
+              (?<=\p{{Lu}}) s ' (?= [,;:{SENTENCE_TERMINALS}]+ $ )"#
+        );
+        let leading_dot = r#"| # Leading dot of a decimal number (e.g., ".5 g"), at the token's start
+              (?<! [\w.] ) \. (?=\d)"#;
+        (acronym_possessive, leading_dot)
+    };
     Regex::new(&format!(
         r#"(?ux)
             (?:
@@ -23,13 +76,19 @@ pub static WORD_BITS: LazyLock<Regex> = LazyLock::new(|| {
                 # Hyphen, surrounded by digits (e.g., DNA endings: "5'-ACGT-3'") or letters
                 # incl. optional apostrophe for DNA segments
               | {NON_QUOTE_APOSTROPHE}? {HYPHEN} (?={ALPHA_NUM})
+                # Underscore, surrounded by digits or letters (if enabled)
+              {underscore}
               )
+            {leading_dot}
             | # Colon, surrounded by digits (e.g., time, references)
               {NUMBER} : (?={NUMBER})
+            | # Space, surrounded by digits (e.g., "100 000"), if not split by the spaces before
+              {NUMBER} \p{{Zs}} (?={NUMBER})
             | # Apostophes, non-consecutive
               {NON_QUOTE_APOSTROPHE} (?!{NON_QUOTE_APOSTROPHE})
             | # ASCII single quote after an s and at the token's end
               s ' $
+            {acronym_possessive}
             | # Terminal dimensions (superscript minus, 1, 2, and 3) attached to physical units
               #   size-prefix           unit-acronym     dimension
               \b [yzafpnµmcdhkMGTPEZY]? {LETTER}{{1,3}} ⁻?[¹²³] $
@@ -39,10 +98,11 @@ pub static WORD_BITS: LazyLock<Regex> = LazyLock::new(|| {
             | # Any (Unicode) letter, digit, or the underscore
               {ALPHA_NUM}
             )+
-        "#
+        "#,
+        NON_QUOTE_APOSTROPHE = NON_QUOTE_APOSTROPHE.as_str(),
     ))
     .unwrap()
-});
+}
 
 /// This tokenizer extends the alphanumeric [symbol_tokenizer](crate::tokenizer::symbol_tokenizer)
 /// by splitting fewer cases.
@@ -51,7 +111,8 @@ pub static WORD_BITS: LazyLock<Regex> = LazyLock::new(|| {
 ///    in a sentence if that dot is the sentence terminal. Therefore, abbreviation marks (words
 ///    containing or ending in a ``.``, like "i.e.") remain intact and URL or ID segments remain
 ///    complete ("www.ex-ample.com", "EC1.2.3.4.5", etc.). The only dots that never are attached
-///    are triple dots (``...``; ellipsis).
+///    are triple dots (``...``; ellipsis). A dot before digits at the start of a token is part of
+///    the number, as in ".5 grams".
 /// 2. Commas surrounded by alphanumeric characters are maintained in the word, too, e.g. ``a,b``.
 ///    Colons surrounded by digits are maintained, e.g., 'at 12:30pm' or 'Isaiah 12:3'.
 ///    Commas, semi-colons, and colons dangling at the end of a token are always spliced off.
@@ -69,47 +130,356 @@ pub static WORD_BITS: LazyLock<Regex> = LazyLock::new(|| {
 ///    in the range from yocto, y (10^-24) to yotta, Y (10^+24)).
 /// 6. Subscript digits are attached if prefixed with letters that look like a chemical formula.
 pub fn word_tokenizer(sentence: &str) -> Vec<String> {
-    let pruned = HYPHENATED_LINEBREAK.replace_all(sentence, |caps: &Captures| format!("{}{}", &caps[1], &caps[2]));
+    word_tokenizer_with(sentence, Default::default())
+}
+
+/// The [word_tokenizer] with the options of a [TokenizeConfig].
+pub fn word_tokenizer_with(sentence: &str, cfg: TokenizeConfig) -> Vec<String> {
+    let pruned = prune_hyphenated_linebreaks(sentence);
+
+    // we can't return reference the pruned string
+    word_tokens(&pruned, cfg).into_iter().map(|token| normalize(token, cfg)).collect()
+}
+
+/// The [word_tokenizer], but each token is paired with a flag whether it was preceded by whitespace.
+/// Useful to restore the original spacing when detokenizing.
+///
+/// ```
+/// # use segtok::tokenizer::word_tokenizer_spacing;
+/// let tokens = word_tokenizer_spacing("a , b.");
+/// assert_eq!(tokens, [("a".into(), false), (",".into(), true), ("b".into(), true), (".".into(), false)]);
+/// ```
+pub fn word_tokenizer_spacing(sentence: &str) -> Vec<(String, bool)> {
+    let cfg = TokenizeConfig::default();
+    let pruned = prune_hyphenated_linebreaks(sentence);
+
+    word_tokens(&pruned, cfg)
+        .into_iter()
+        .map(|token| {
+            let start = token.as_ptr() as usize - pruned.as_ptr() as usize;
+            let spaced = pruned[..start].ends_with(char::is_whitespace);
+            (normalize(token, cfg), spaced)
+        })
+        .collect()
+}
+
+/// The [word_tokenizer], but each token is paired with its byte range in the sentence.
+///
+/// The tokens borrow from the sentence, except for the words hyphenated at linebreaks, like "catch-\nup":
+/// they are joined to "catch-up", while their range covers the original text, including the linebreak.
+///
+/// ```
+/// # use segtok::tokenizer::word_tokenizer_spans;
+/// let tokens = word_tokenizer_spans("A catch-\n up.");
+/// assert_eq!(tokens, [("A".into(), 0..1), ("catch-up".into(), 2..12), (".".into(), 12..13)]);
+/// ```
+pub fn word_tokenizer_spans(sentence: &str) -> Vec<(Cow<'_, str>, Range<usize>)> {
+    // the pruned positions of the removed linebreaks, and the byte lengths removed there
+    let mut cuts = Vec::new();
+    let mut pruned = String::new();
+    let mut cursor = 0;
+
+    if sentence.contains(['\n', '\r', '\u{2028}']) {
+        for caps in HYPHENATED_LINEBREAK.captures_iter(sentence).map(Result::unwrap) {
+            let (hyphen_end, word_start) = (caps.get(1).unwrap().end(), caps.get(2).unwrap().start());
+            pruned.push_str(&sentence[cursor..hyphen_end]);
+            cuts.push((pruned.len(), word_start - hyphen_end));
+            cursor = word_start;
+        }
+    }
+    pruned.push_str(&sentence[cursor..]);
+
+    // a token that starts at a cut starts after the linebreak, while one that ends there ends before it
+    let removed_before = |pos: usize, inclusive: bool| -> usize {
+        cuts.iter().take_while(|&&(cut, _)| cut < pos || inclusive && cut == pos).map(|&(_, len)| len).sum()
+    };
+
+    word_tokens(&pruned, TokenizeConfig::default())
+        .into_iter()
+        .map(|token| {
+            let start = token.as_ptr() as usize - pruned.as_ptr() as usize;
+            let end = start + token.len();
+            let range = start + removed_before(start, true)..end + removed_before(end, false);
+            let text = match &sentence[range.clone()] {
+                original if original == token => Cow::Borrowed(original),
+                _ => Cow::Owned(token.to_owned()),
+            };
+            (text, range)
+        })
+        .collect()
+}
+
+/// The [word_tokenizer], but the punctuation attached to the end of a token is kept in its
+/// [trailing_punct](TriviaToken::trailing_punct), and the whitespace before it in its
+/// [leading_ws](TriviaToken::leading_ws), so the sentence can be reconstructed exactly.
+///
+/// Words hyphenated at linebreaks are not joined, and the whitespace at the end of the sentence
+/// is the `leading_ws` of a last token without text.
+///
+/// ```
+/// # use segtok::tokenizer::word_tokenizer_trivia;
+/// let tokens = word_tokenizer_trivia("call(x); done");
+/// assert_eq!(tokens.iter().map(|token| token.text.as_str()).collect::<Vec<_>>(), ["call", "x", "done"]);
+/// assert_eq!(tokens[1].trailing_punct, ");");
+/// assert_eq!(tokens[2].leading_ws, " ");
+/// ```
+pub fn word_tokenizer_trivia(sentence: &str) -> Vec<TriviaToken> {
+    let mut res: Vec<TriviaToken> = Vec::new();
+    let mut cursor = 0;
+
+    for token in word_tokens(sentence, TokenizeConfig::default()) {
+        let start = token.as_ptr() as usize - sentence.as_ptr() as usize;
+        let gap = &sentence[cursor..start];
+        cursor = start + token.len();
+
+        match res.last_mut() {
+            Some(last) if gap.is_empty() && !token.chars().any(char::is_alphanumeric) => {
+                last.trailing_punct.push_str(token)
+            }
+            _ => res.push(TriviaToken { leading_ws: gap.to_owned(), text: token.to_owned(), ..Default::default() }),
+        }
+    }
+
+    if cursor < sentence.len() {
+        res.push(TriviaToken { leading_ws: sentence[cursor..].to_owned(), ..Default::default() });
+    }
+
+    res
+}
+
+/// Join words hyphenated at linebreaks, like "catch-\nup".
+fn prune_hyphenated_linebreaks(sentence: &str) -> Cow<'_, str> {
+    // most sentences have no linebreaks at all, so don't run the regex on them
+    if sentence.contains(['\n', '\r', '\u{2028}']) {
+        HYPHENATED_LINEBREAK.replace_all(sentence, |caps: &Captures| format!("{}{}", &caps[1], &caps[2]))
+    } else {
+        Cow::Borrowed(sentence)
+    }
+}
+
+fn normalize(token: &str, cfg: TokenizeConfig) -> String {
+    match token {
+        "--" if cfg.normalize_double_hyphen => "—".to_owned(),
+        _ => token.to_owned(),
+    }
+}
+
+/// The same as the [space_tokenizer], but with the [SpaceConfig] and the script separators of the `cfg`,
+/// and optionally keeps thin and hair spaces between digits, as in "100 000".
+fn split_spaces(sentence: &str, cfg: TokenizeConfig) -> Vec<&str> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    let mut prev = None;
+    let mut chars = sentence.char_indices().peekable();
 
-    let (mut tokens, is_word_bit): (Vec<_>, Vec<_>) = space_tokenizer(&pruned)
-        .flat_map(|span| PartitionIter::new(&WORD_BITS, span).filter(|&s| !s.as_ref().is_empty()))
+    while let Some((idx, ch)) = chars.next() {
+        let in_number = cfg.thin_space_in_numbers
+            && matches!(ch, '\u{2009}' | '\u{200A}')
+            && prev.is_some_and(char::is_numeric)
+            && chars.peek().is_some_and(|&(_, next)| next.is_numeric());
+
+        if !cfg.is_space(ch) || in_number {
+            start = start.or(Some(idx));
+        } else if let Some(start) = start.take() {
+            spans.push(&sentence[start..idx]);
+        }
+
+        prev = Some(ch);
+    }
+
+    spans.extend(start.map(|start| &sentence[start..]));
+    spans
+}
+
+/// Join the middle dots with the tokens right around them, if the `policy` allows it.
+fn join_middots<'a>(pruned: &'a str, tokens: &mut Vec<&'a str>, is_word_bit: &mut Vec<bool>, policy: MiddotPolicy) {
+    let offset = |token: &str| token.as_ptr() as usize - pruned.as_ptr() as usize;
+    let is_unit = |word: &str| {
+        let letters = word.trim_end_matches(['⁻', '¹', '²', '³']);
+        (1..=3).contains(&letters.chars().count()) && letters.chars().all(char::is_alphabetic)
+    };
+
+    let mut idx = 1;
+    while idx + 1 < tokens.len() {
+        let (prev, dot, next) = (tokens[idx - 1], tokens[idx], tokens[idx + 1]);
+        // only the part after the last joined dot matters, as in "kg·m" before "·s⁻²"
+        let left = prev.rsplit('·').next().unwrap_or(prev);
+        let adjacent = offset(dot) == offset(prev) + prev.len() && offset(next) == offset(dot) + dot.len();
+        let joins = match policy {
+            MiddotPolicy::Separator => false,
+            MiddotPolicy::UnitJoin => is_unit(left) && is_unit(next),
+            MiddotPolicy::WordJoin => {
+                is_unit(left) && is_unit(next)
+                    || left.ends_with(char::is_alphabetic) && next.starts_with(char::is_alphabetic)
+            }
+        };
+
+        if dot == "·" && adjacent && joins {
+            tokens.splice(idx - 1..=idx + 1, [&pruned[offset(prev)..offset(next) + next.len()]]);
+            is_word_bit.splice(idx - 1..=idx + 1, [true]);
+        } else {
+            idx += 1;
+        }
+    }
+}
+
+/// Replace the tokens that overlap [MEASUREMENT]s with the measurements,
+/// keeping the parts of the tokens outside of them, as in "6'2" and "\"" becoming "6'" and "2\"".
+fn join_measurements<'a>(pruned: &'a str, tokens: &mut Vec<&'a str>, is_word_bit: &mut Vec<bool>) {
+    let measurements = MEASUREMENT
+        .captures_iter(pruned)
+        .flat_map(|caps| caps.unwrap().iter().skip(1).flatten().map(|m| m.range()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    join_ranges(pruned, tokens, is_word_bit, measurements);
+}
+
+/// Replace the tokens that overlap [SEMVER]s with the versions, as in "1.0.0-rc.1", "+", "build.5".
+fn join_semvers<'a>(pruned: &'a str, tokens: &mut Vec<&'a str>, is_word_bit: &mut Vec<bool>) {
+    let versions = SEMVER.find_iter(pruned).map(|m| m.unwrap().range()).collect::<Vec<_>>();
+    join_ranges(pruned, tokens, is_word_bit, versions);
+}
+
+/// Replace the tokens that overlap [INLINE_MATH] with the math, as in "$x", "=", "3.14$" becoming "$x = 3.14$".
+fn join_math<'a>(pruned: &'a str, tokens: &mut Vec<&'a str>, is_word_bit: &mut Vec<bool>) {
+    let math = INLINE_MATH.find_iter(pruned).map(|m| m.unwrap().range()).collect::<Vec<_>>();
+    join_ranges(pruned, tokens, is_word_bit, math);
+}
+
+/// Replace the tokens that overlap the sorted `ranges` of the `pruned` text with the ranges,
+/// keeping the parts of the tokens outside of them.
+fn join_ranges<'a>(pruned: &'a str, tokens: &mut Vec<&'a str>, is_word_bit: &mut Vec<bool>, ranges: Vec<Range<usize>>) {
+    if ranges.is_empty() {
+        return;
+    }
+
+    let offset = |token: &str| token.as_ptr() as usize - pruned.as_ptr() as usize;
+    let mut res = Vec::with_capacity(tokens.len());
+    let mut ranges = ranges.into_iter().peekable();
+
+    for (token, is_word_bit) in tokens.iter().copied().zip(is_word_bit.iter().copied()) {
+        let (mut start, end) = (offset(token), offset(token) + token.len());
+
+        while let Some(range) = ranges.next_if(|range| range.start < end) {
+            if start < range.start {
+                res.push((&pruned[start..range.start], is_word_bit));
+            }
+            res.push((&pruned[range.clone()], true));
+            start = start.max(range.end);
+        }
+
+        // the rest of a range that spans into the next tokens is skipped
+        let covered = res.last().map_or(0, |(last, _)| offset(last) + last.len());
+        start = start.max(covered);
+        if start < end {
+            res.push((&pruned[start..end], is_word_bit));
+        }
+    }
+
+    (*tokens, *is_word_bit) = res.into_iter().unzip();
+}
+
+/// Join the degree signs, and the temperature units after them, with the numbers right before them.
+fn join_degrees<'a>(pruned: &'a str, tokens: &mut Vec<&'a str>) {
+    let offset = |token: &str| token.as_ptr() as usize - pruned.as_ptr() as usize;
+    let is_adjacent = |prev: &str, next: &str| offset(next) == offset(prev) + prev.len();
+    let is_number = |token: &str| token.starts_with(|ch: char| ch.is_ascii_digit()) && token.parse::<f64>().is_ok();
+
+    let mut idx = 1;
+    while idx < tokens.len() {
+        let (number, degree) = (tokens[idx - 1], tokens[idx]);
+
+        if is_number(number) && matches!(degree, "°" | "℃" | "℉") && is_adjacent(number, degree) {
+            let unit = tokens
+                .get(idx + 1)
+                .filter(|&&unit| degree == "°" && matches!(unit, "C" | "F" | "K") && is_adjacent(degree, unit));
+            let last = unit.map_or(idx, |_| idx + 1);
+            let end = offset(tokens[last]) + tokens[last].len();
+            tokens.splice(idx - 1..=last, [&pruned[offset(number)..end]]);
+        }
+
+        idx += 1;
+    }
+}
+
+/// Split the `pruned` sentence into tokens, which are its slices.
+fn word_tokens(pruned: &str, cfg: TokenizeConfig) -> Vec<&str> {
+    let word_bits = match (cfg.python_compat, cfg.keep_underscores) {
+        (true, underscores) => &PYTHON_WORD_BITS[underscores as usize],
+        (false, true) => &*WORD_BITS_WITH_UNDERSCORES,
+        (false, false) => &*WORD_BITS,
+    };
+
+    let spans = if cfg.thin_space_in_numbers || cfg.spaces != SpaceConfig::default() || cfg.script.is_some() {
+        Either::Left(split_spaces(pruned, cfg).into_iter())
+    } else {
+        Either::Right(space_tokenizer(pruned))
+    };
+
+    let (mut tokens, mut is_word_bit): (Vec<_>, Vec<_>) = spans
+        .flat_map(|span| PartitionIter::new(word_bits, span).filter(|&s| !s.as_ref().is_empty()))
         .map(Partition::into_pair)
         .unzip();
 
+    if cfg.middot_policy != MiddotPolicy::Separator && pruned.contains('·') {
+        join_middots(pruned, &mut tokens, &mut is_word_bit, cfg.middot_policy);
+    }
+
+    if cfg.measurement_primes && pruned.contains(['\'', '"', '′', '″']) {
+        join_measurements(pruned, &mut tokens, &mut is_word_bit);
+    }
+
+    if cfg.keep_semver && pruned.contains(['-', '+']) {
+        join_semvers(pruned, &mut tokens, &mut is_word_bit);
+    }
+
+    if cfg.inline_math && pruned.contains(['$', '\\']) {
+        join_math(pruned, &mut tokens, &mut is_word_bit);
+    }
+
     // splice the sentence terminal off the last word/token if it has any at its borders
     // only look for the sentence terminal in the last three tokens
     let last_three = tokens.iter().copied().zip(is_word_bit.iter().copied()).enumerate().rev().take(3);
+    let is_terminal =
+        |ch: char| is_sentence_terminal(ch) || cfg.script.is_some_and(|script| script.terminals().contains(&ch));
 
     for (idx, (word, is_word_bit)) in last_three {
-        if is_word_bit && !word.chars().any(is_non_quote_apostrophe)
-            || word.chars().last().is_some_and(is_sentence_terminal)
-            || word.chars().next().is_some_and(is_sentence_terminal)
-        {
-            if word.chars().count() == 1 || word == "..." {
-                break; // leave the token as it is
-            }
+        let first = word.chars().next().filter(|&first| is_terminal(first));
+        let last = word.char_indices().next_back().filter(|&(_, last)| is_terminal(last));
 
-            if let Some((pos, _)) = word.char_indices().last().filter(|&(_, last)| is_sentence_terminal(last)) {
-                // stuff.
-                let (prefix, suffix) = word.split_at(pos);
-                tokens[idx] = prefix;
-                tokens.insert(idx + 1, suffix);
-            } else if let Some((pos, ch)) = word.char_indices().next().filter(|&(_, first)| is_sentence_terminal(first))
-            {
-                // .stuff
-                let (prefix, suffix) = word.split_at(pos + ch.len_utf8());
-                tokens[idx] = prefix;
-                tokens.insert(idx + 1, suffix);
+        if first.is_none() && last.is_none() {
+            // fast path: a plain word stops the search, anything else is skipped
+            if is_word_bit && !word.chars().any(is_non_quote_apostrophe) {
+                break;
             }
+            continue;
+        }
 
-            break;
+        if word.chars().nth(1).is_none() || word == "..." {
+            break; // leave the token as it is
         }
+
+        if let Some((pos, _)) = last {
+            // stuff.
+            let (prefix, suffix) = word.split_at(pos);
+            tokens[idx] = prefix;
+            tokens.insert(idx + 1, suffix);
+        } else if let Some(first) =
+            first.filter(|&first| cfg.python_compat || first != '.' || !word[1..].starts_with(char::is_numeric))
+        {
+            // .stuff, but not a decimal number like .5, unless in the python_compat mode
+            let (prefix, suffix) = word.split_at(first.len_utf8());
+            tokens[idx] = prefix;
+            tokens.insert(idx + 1, suffix);
+        }
+
+        break;
     }
 
     // keep splicing off any dangling commas and (semi-) colons
     for idx in (0..tokens.len()).rev() {
         let word = tokens[idx];
-        if word.chars().count() <= 1 {
+        if word.len() <= 1 || !word.ends_with([',', ';', ':']) {
             continue;
         }
         if let Some((pos, _)) = word.char_indices().rev().take_while(|&(_, ch)| matches!(ch, ',' | ';' | ':')).last() {
@@ -120,8 +490,11 @@ pub fn word_tokenizer(sentence: &str) -> Vec<String> {
         }
     }
 
-    // we can't return reference the pruned string
-    tokens.into_iter().map(ToOwned::to_owned).collect()
+    if cfg.attach_degrees && pruned.contains(['°', '℃', '℉']) {
+        join_degrees(pruned, &mut tokens);
+    }
+
+    tokens
 }
 
 #[allow(clippy::needless_borrow)]
@@ -167,6 +540,87 @@ mod tests {
         assert_eq!(word_tokenizer(&input), expected);
     }
 
+    #[test]
+    fn spacing() {
+        let input = "a,b a , b, c.";
+        let expected = [("a,b", false), ("a", true), (",", true), ("b", true), (",", false), ("c", true), (".", false)];
+        assert_eq!(word_tokenizer_spacing(&input), expected.map(|(token, spaced)| (token.to_owned(), spaced)));
+    }
+
+    #[test]
+    fn spacing_after_hyphenated_linebreak() {
+        let input = " catch-\n up.";
+        let expected = [("catch-up", true), (".", false)];
+        assert_eq!(word_tokenizer_spacing(&input), expected.map(|(token, spaced)| (token.to_owned(), spaced)));
+    }
+
+    #[test]
+    fn spans() {
+        let inputs = [
+            "a,b a , b, c.",
+            " catch-\n up and re-\r\nturn, well-\nknown.",
+            "Ünïcode co-\n\top, 5'-ACGT-3' here.",
+            "No linebreaks at all.",
+            "",
+        ];
+        for input in inputs {
+            let tokens = word_tokenizer_spans(input);
+            let texts = tokens.iter().map(|(token, _)| token.to_string()).collect::<Vec<_>>();
+            assert_eq!(texts, word_tokenizer(input));
+
+            for (token, range) in tokens {
+                match token {
+                    Cow::Borrowed(token) => assert_eq!(&input[range], token),
+                    Cow::Owned(token) => assert_eq!(prune_hyphenated_linebreaks(&input[range]), token),
+                }
+            }
+        }
+
+        let expected = [("So".into(), 0..2), ("re-turn".into(), 3..13), (",".into(), 13..14)];
+        assert_eq!(word_tokenizer_spans("So re-\r\n turn,"), expected);
+    }
+
+    #[test]
+    fn trivia() {
+        let actual = word_tokenizer_trivia(" if (a, b) { f(x); } ");
+        let expected = [
+            (" ", "if", ""),
+            (" ", "(", ""),
+            ("", "a", ","),
+            (" ", "b", ")"),
+            (" ", "{", ""),
+            (" ", "f", "("),
+            ("", "x", ");"),
+            (" ", "}", ""),
+            (" ", "", ""),
+        ];
+        let expected = expected.map(|(leading_ws, text, trailing_punct)| TriviaToken {
+            leading_ws: leading_ws.to_owned(),
+            text: text.to_owned(),
+            trailing_punct: trailing_punct.to_owned(),
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn trivia_round_trip() {
+        let inputs = [
+            "",
+            "  ",
+            "a,b a , b, c.",
+            " catch-\n up.",
+            "// TODO: fix `foo::bar()` -- see #12 (v1.2.3)!\n",
+            "x\t=\r\n  y[0] + 1e-3;  ",
+            "He said: \"Don’t!\" and left…",
+        ];
+        for input in inputs {
+            let tokens = word_tokenizer_trivia(input);
+            let output =
+                tokens.iter().map(|t| format!("{}{}{}", t.leading_ws, t.text, t.trailing_punct)).collect::<String>();
+            assert_eq!(output, input);
+        }
+    }
+
     #[test]
     fn with_dangling_hyphen() {
         test_dangling('-')
@@ -234,6 +688,14 @@ mod tests {
         assert_eq!(word_tokenizer(&input), expected);
     }
 
+    #[test]
+    fn hyphen_repeat_normalized() {
+        let cfg = TokenizeConfig::default().normalize_double_hyphen(true);
+        let input = "A--B -- C---D";
+        let expected = ["A", "—", "B", "—", "C", "---", "D"];
+        assert_eq!(word_tokenizer_with(&input, cfg), expected);
+    }
+
     #[test]
     fn comma_repeat() {
         let input = "A,,B";
@@ -348,6 +810,19 @@ mod tests {
         assert_eq!(word_tokenizer(&input), expected);
     }
 
+    #[test]
+    fn possesive_s_ascii_apostrophe_before_punctuation() {
+        let input = "The URLs' formats, the CPUs'; PhDs'. Not 'rumors'.";
+        let expected =
+            ["The", "URLs'", "formats", ",", "the", "CPUs'", ";", "PhDs'", ".", "Not", "'", "rumors", "'", "."];
+        assert_eq!(word_tokenizer(&input), expected);
+
+        let cfg = TokenizeConfig::default().python_compat(true);
+        let expected =
+            ["The", "URLs'", "formats", ",", "the", "CPUs", "'", ";", "PhDs", "'.", "Not", "'", "rumors", "'", "."];
+        assert_eq!(word_tokenizer_with(&input, cfg), expected);
+    }
+
     #[test]
     fn apostrophe_unicode() {
         let input = "\u{2019}tis less\u{02BC} O\u{2019}Neil\u{02BC}s";
@@ -355,6 +830,13 @@ mod tests {
         assert_eq!(word_tokenizer(&input), expected);
     }
 
+    #[test]
+    fn okina() {
+        let input = "The \u{02BB}ukulele of Hawai\u{02BB}i's O\u{02BB}ahu.";
+        let expected = ["The", "\u{02BB}ukulele", "of", "Hawai\u{02BB}i's", "O\u{02BB}ahu", "."];
+        assert_eq!(word_tokenizer(&input), expected);
+    }
+
     #[test]
     fn hyphen_dot_apostrophe() {
         let input = " O.h'Ne.l- \n l's ";
@@ -362,6 +844,20 @@ mod tests {
         assert_eq!(word_tokenizer(&input), expected);
     }
 
+    #[test]
+    fn leading_dot_decimals() {
+        let input = "Add .5 g (.25) or .25% to -.75 of .5.";
+        let expected = ["Add", ".5", "g", "(", ".25", ")", "or", ".25", "%", "to", "-", ".75", "of", ".5", "."];
+        assert_eq!(word_tokenizer(&input), expected);
+
+        assert_eq!(word_tokenizer("See .NET and ...5 here."), ["See", ".", "NET", "and", "...", "5", "here", "."]);
+
+        // the python package splits the leading dot off
+        let cfg = TokenizeConfig::default().python_compat(true);
+        assert_eq!(word_tokenizer_with(".5 g", cfg), [".", "5", "g"]);
+        assert_eq!(word_tokenizer_with("Add .25% here.", cfg), ["Add", ".", "25", "%", "here", "."]);
+    }
+
     #[test]
     fn numbers() {
         let input = "$123,456.99 45.67+/-1.23%";
@@ -398,4 +894,154 @@ mod tests {
             ["http", "://", "www.example.com", "/", "path", "/", "to.file", "?", "kwd", "=", "1", "&", "arg"];
         assert_eq!(word_tokenizer(&input), expected);
     }
+
+    #[test]
+    fn underscores_split_by_default() {
+        let input = "snake_case";
+        let expected = ["snake", "_", "case"];
+        assert_eq!(word_tokenizer(&input), expected);
+    }
+
+    #[test]
+    fn underscores_kept() {
+        let cfg = TokenizeConfig::default().keep_underscores(true);
+        let input = "call snake_case or __init__ with a__b_c.";
+        let expected = ["call", "snake_case", "or", "__", "init", "__", "with", "a", "__", "b_c", "."];
+        assert_eq!(word_tokenizer_with(&input, cfg), expected);
+    }
+
+    #[test]
+    fn middot_policies() {
+        let input = "10 V·m⁻¹ and kg·m·s⁻² in la col·lecció, a · b.";
+        let tokenize = |policy| word_tokenizer_with(&input, TokenizeConfig::default().middot_policy(policy));
+
+        let expected = [
+            "10", "V", "·", "m⁻¹", "and", "kg", "·", "m", "·", "s⁻²", "in", "la", "col", "·", "lecció", ",", "a", "·",
+            "b", ".",
+        ];
+        assert_eq!(tokenize(MiddotPolicy::Separator), expected);
+
+        let expected = ["10", "V·m⁻¹", "and", "kg·m·s⁻²", "in", "la", "col", "·", "lecció", ",", "a", "·", "b", "."];
+        assert_eq!(tokenize(MiddotPolicy::UnitJoin), expected);
+
+        let expected = ["10", "V·m⁻¹", "and", "kg·m·s⁻²", "in", "la", "col·lecció", ",", "a", "·", "b", "."];
+        assert_eq!(tokenize(MiddotPolicy::WordJoin), expected);
+    }
+
+    #[test]
+    fn section_signs() {
+        let input = "§ 5 and §§ 3-4 apply ¶12 here. ¶ New";
+        assert_eq!(word_tokenizer(input), ["§", "5", "and", "§§", "3-4", "apply", "¶", "12", "here.", "¶", "New"]);
+    }
+
+    #[test]
+    fn attach_degrees() {
+        let cfg = TokenizeConfig::default().attach_degrees(true);
+        let input = "At 37°C, or 98.6°F, a 90° angle and -5 ° C. Not 4°Celsius or 20℃.";
+        let expected = [
+            "At", "37°C", ",", "or", "98.6°F", ",", "a", "90°", "angle", "and", "-", "5", "°", "C.", "Not", "4°",
+            "Celsius", "or", "20℃", ".",
+        ];
+        assert_eq!(word_tokenizer_with(&input, cfg), expected);
+        assert_eq!(word_tokenizer("At 37°C."), ["At", "37", "°", "C", "."]);
+    }
+
+    #[test]
+    fn inline_math() {
+        let cfg = TokenizeConfig::default().inline_math(true);
+        let input = "The value $x = 3.14$, or $$a. b$$ and \\(p, q\\), not $5 and $10.";
+        let expected = [
+            "The",
+            "value",
+            "$x = 3.14$",
+            ",",
+            "or",
+            "$$a. b$$",
+            "and",
+            "\\(p, q\\)",
+            ",",
+            "not",
+            "$",
+            "5",
+            "and",
+            "$",
+            "10",
+            ".",
+        ];
+        assert_eq!(word_tokenizer_with(&input, cfg), expected);
+        assert_eq!(word_tokenizer_with("It is $n!$.", cfg), ["It", "is", "$n!$", "."]);
+        assert_eq!(word_tokenizer("It is $n!$."), ["It", "is", "$", "n", "!$", "."]);
+    }
+
+    #[test]
+    fn keep_semver() {
+        let cfg = TokenizeConfig::default().keep_semver(true);
+        let input = "Install v2.3.4 or 1.0.0-rc.1+build.5, not 1.0.0+20130313144700 (or 2.0.0-x.7.z.92).";
+        let expected = [
+            "Install",
+            "v2.3.4",
+            "or",
+            "1.0.0-rc.1+build.5",
+            ",",
+            "not",
+            "1.0.0+20130313144700",
+            "(",
+            "or",
+            "2.0.0-x.7.z.92",
+            ")",
+            ".",
+        ];
+        assert_eq!(word_tokenizer_with(&input, cfg), expected);
+        assert_eq!(word_tokenizer_with("Version 1.0.0-rc.1+build.5.", cfg), ["Version", "1.0.0-rc.1+build.5", "."]);
+        assert_eq!(word_tokenizer_with("Not 1.2+3 or 1.2.3+", cfg), ["Not", "1.2", "+", "3", "or", "1.2.3", "+"]);
+        assert_eq!(word_tokenizer("1.0.0-rc.1+build.5"), ["1.0.0-rc.1", "+", "build.5"]);
+    }
+
+    #[test]
+    fn measurement_primes() {
+        let cfg = TokenizeConfig::default().measurement_primes(true);
+        let tokenize = |input| word_tokenizer_with(input, cfg);
+
+        assert_eq!(tokenize("He is 5' 10\" tall."), ["He", "is", "5'", "10\"", "tall", "."]);
+        assert_eq!(tokenize("He is 6'2\"."), ["He", "is", "6'", "2\"", "."]);
+        assert_eq!(tokenize("(5.5′ 3″, or 7′)"), ["(", "5.5′", "3″", ",", "or", "7′", ")"]);
+        assert_eq!(
+            tokenize("Say \"10\" or 'x' in the '90s and 5'."),
+            word_tokenizer("Say \"10\" or 'x' in the '90s and 5'.")
+        );
+        assert_eq!(word_tokenizer("He is 5' 10\" tall."), ["He", "is", "5", "'", "10", "\"", "tall", "."]);
+    }
+
+    #[test]
+    fn ethiopic_script() {
+        use crate::segmenter::Script;
+
+        let cfg = TokenizeConfig::default().script(Some(Script::Ethiopic));
+        assert_eq!(word_tokenizer_with("ሰላም፡ነው።", cfg), ["ሰላም", "ነው", "።"]);
+        assert_eq!(word_tokenizer("ሰላም፡ነው።"), ["ሰላም፡ነው።"]);
+    }
+
+    #[test]
+    fn excluded_spaces() {
+        let cfg = TokenizeConfig::default().spaces(SpaceConfig::default().exclude(['\u{00A0}', '\u{2007}']));
+        let input = "It is 1\u{2007}000 or 2\u{00A0}000 km, not 3\u{2009}000.";
+        let expected = ["It", "is", "1\u{2007}000", "or", "2\u{00A0}000", "km", ",", "not", "3", "000", "."];
+        assert_eq!(word_tokenizer_with(&input, cfg), expected);
+    }
+
+    #[test]
+    fn thin_spaces_split_by_default() {
+        let input = "Il y a 100\u{2009}000 habitants.";
+        let expected = ["Il", "y", "a", "100", "000", "habitants", "."];
+        assert_eq!(word_tokenizer(&input), expected);
+    }
+
+    #[test]
+    fn thin_spaces_in_numbers() {
+        let cfg = TokenizeConfig::default().thin_space_in_numbers(true);
+        let input = "Il y a 100\u{2009}000 et 1\u{200A}234\u{200A}567,5 habitants\u{2009}: 12\u{2009}ans.";
+        let expected =
+            ["Il", "y", "a", "100\u{2009}000", "et", "1\u{200A}234\u{200A}567,5", "habitants", ":", "12", "ans", "."];
+        assert_eq!(word_tokenizer_with(&input, cfg), expected);
+    }
 }
@@ -0,0 +1,15 @@
+use segtok::segmenter::{set_default_short_sentence_length, split_multi, SegmentConfig};
+
+// the default is global, so it is only changed in this test binary
+#[test]
+fn short_sentence_length() {
+    let text = "Values (see Fig. 2. Left) differ.";
+    assert_eq!(split_multi(text, Default::default()), [text]);
+
+    set_default_short_sentence_length(5);
+    assert_eq!(split_multi(text, Default::default()), ["Values (see Fig. 2.", "Left) differ."]);
+    assert_eq!(split_multi(text, SegmentConfig::default().short_sentence_length(55)), [text]);
+
+    set_default_short_sentence_length(55);
+    assert_eq!(split_multi(text, Default::default()), [text]);
+}
@@ -1,5 +1,7 @@
 use segtok::segmenter::split_multi;
-use segtok::tokenizer::{split_contractions, web_tokenizer};
+use segtok::tokenizer::{
+    split_contractions, split_contractions_with, web_tokenizer, web_tokenizer_with, TokenizeConfig,
+};
 
 #[test]
 fn turkish() {
@@ -40,3 +42,30 @@ fn google() {
 
     assert_eq!(sentences, expected);
 }
+
+/// The tokens of the python package, which the reference files were made with.
+fn python_tokens(input: &str, is_dropped: impl Fn(&str) -> bool) -> Vec<Vec<String>> {
+    let cfg = TokenizeConfig::default().python_compat(true);
+
+    split_multi(input, Default::default())
+        .into_iter()
+        .filter(|span| !span.is_empty())
+        .map(|span| split_contractions_with(web_tokenizer_with(&span, cfg), cfg))
+        .map(|tokens| tokens.into_iter().filter(|word| !is_dropped(word)).collect())
+        .collect()
+}
+
+#[test]
+fn python_compat() {
+    let turkish = python_tokens(include_str!("test_turkish.txt"), |word| {
+        word.is_empty() || word.chars().count() > 1 && word.starts_with("'")
+    });
+    let expected: Vec<Vec<String>> = serde_json::from_str(include_str!("test_turkish_reference.json")).unwrap();
+    assert_eq!(turkish, expected);
+
+    let google = python_tokens(include_str!("test_google.txt"), |word| {
+        word.is_empty() || word.len() > 1 && word.starts_with("'")
+    });
+    let expected: Vec<Vec<String>> = serde_json::from_str(include_str!("test_google_reference.json")).unwrap();
+    assert_eq!(google, expected);
+}
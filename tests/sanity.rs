@@ -16,3 +16,34 @@ fn check_text_is_segmented_without_panics() {
         })
         .collect();
 }
+
+#[test]
+fn check_long_references_line_is_segmented() {
+    let input = include_str!("test_references.txt");
+    assert_eq!(input.lines().count(), 1);
+
+    let sentences = split_multi(input, Default::default());
+    assert!(sentences.len() > 1000);
+    assert!(sentences.iter().all(|sentence| !sentence.is_empty()));
+}
+
+#[test]
+fn check_no_empty_sentences() {
+    for input in [include_str!("test_business.txt"), include_str!("test_google.txt"), include_str!("test_turkish.txt")]
+    {
+        let sentences = split_multi(input, Default::default());
+        assert!(sentences.iter().all(|sentence| !sentence.trim().is_empty()));
+    }
+}
+
+#[test]
+fn check_multiline_sentences_are_tokenized_like_one_line() {
+    let input = "This is a\nmultiline sentence with\r\nten words,\n\tsplit over lines.\nAnd a second\none.";
+    let sentences = split_multi(input, Default::default());
+    assert_eq!(sentences.len(), 2);
+
+    for sentence in sentences {
+        let flat = sentence.split_whitespace().collect::<Vec<_>>().join(" ");
+        assert_eq!(web_tokenizer(&sentence), web_tokenizer(&flat));
+    }
+}